@@ -0,0 +1,48 @@
+//! Compares single-byte pattern searches over a large byte buffer, written as a bare byte
+//! and as one-element slice, vec and array patterns.
+//!
+//! Run with `cargo bench --features memchr` to measure the `memchr` path.
+
+use split_every::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let line: &[u8] = b"the quick brown fox jumps over the lazy dog\n";
+    let input: Vec<u8> = line.repeat(10_000_000 / line.len());
+    bench("byte pattern", || {
+        input.as_slice().split_every_n_times(b'\n', 1000).count()
+    });
+    bench("one-element slice pattern", || {
+        input
+            .as_slice()
+            .split_every_n_times(&[b'\n'][..], 1000)
+            .count()
+    });
+    bench("one-element vec pattern", || {
+        input
+            .as_slice()
+            .split_every_n_times(vec![b'\n'], 1000)
+            .count()
+    });
+    bench("one-element array pattern", || {
+        input.as_slice().split_every_n_times([b'\n'], 1000).count()
+    });
+    bench("two-element slice pattern", || {
+        input
+            .as_slice()
+            .split_every_n_times(&b"g\n"[..], 1000)
+            .count()
+    });
+}
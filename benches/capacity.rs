@@ -0,0 +1,62 @@
+//! Compares collecting large chunks with and without a capacity hint.
+
+use split_every::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Counts reallocations on top of the system allocator.
+struct CountingAlloc;
+
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    let reallocs: usize = REALLOCS.load(Ordering::Relaxed);
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    let reallocs: usize = REALLOCS.load(Ordering::Relaxed) - reallocs;
+    println!(
+        "{name}: {:?} and {} reallocations per iteration",
+        total / ITERATIONS,
+        reallocs / ITERATIONS as usize
+    );
+}
+
+fn main() {
+    let input: Vec<u32> = (0..1_000_000).map(|val| val % 100).collect();
+    bench("without capacity", || {
+        input.iter().copied().split_every_n_times(0, 100).count()
+    });
+    bench("with capacity", || {
+        input
+            .iter()
+            .copied()
+            .split_every_n_times(0, 100)
+            .chunk_capacity(10_000)
+            .count()
+    });
+}
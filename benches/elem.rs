@@ -0,0 +1,42 @@
+//! Compares splitting a slice on a borrowed element against the slice pattern form.
+
+use split_every::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let input: Vec<i32> = (0..2_000_000).map(|val| val % 7).collect();
+    // Patterns go through `black_box` so no side searches for a constant.
+    bench("split_every_n_times_elem_ref(&0, 1)", || {
+        input
+            .as_slice()
+            .split_every_n_times_elem_ref(black_box(&0), 1)
+            .map(|chunk| chunk.len())
+            .sum()
+    });
+    bench("split_every_n_times(&[0], 1)", || {
+        input
+            .as_slice()
+            .split_every_n_times(black_box(&[0][..]), 1)
+            .map(|chunk| chunk.len())
+            .sum()
+    });
+    bench("split_every_n_times(&[0, 1], 1)", || {
+        input
+            .as_slice()
+            .split_every_n_times(black_box(&[0, 1][..]), 1)
+            .map(|chunk| chunk.len())
+            .sum()
+    });
+}
@@ -0,0 +1,32 @@
+//! Compares `fold`, which copies every chunk, with `fold_chunks`, which borrows them.
+
+use split_every::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let input: String = "the quick brown fox jumps over the lazy dog ".repeat(100_000);
+    bench("owned chunks", || {
+        input
+            .as_str()
+            .split_every_n_times(" ", 3)
+            .fold(0, |total, chunk| total + chunk.len())
+    });
+    bench("borrowed chunks", || {
+        input
+            .as_str()
+            .split_every_n_times(" ", 3)
+            .fold_chunks(0, |total, chunk| total + chunk.len())
+    });
+}
@@ -0,0 +1,30 @@
+//! Compares the boxed and the monomorphized iterator adapters.
+
+use split_every::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let input: Vec<u32> = (0..1_000_000).map(|val| val % 100).collect();
+    bench("boxed", || {
+        input.iter().copied().split_every_n_times(0, 10).count()
+    });
+    bench("monomorphized", || {
+        input
+            .iter()
+            .copied()
+            .split_every_n_times_iter(0, 10)
+            .count()
+    });
+}
@@ -0,0 +1,49 @@
+//! Compares splitting on every occurrence with `n == 1`, which skips the counting loop used
+//! for larger `n`, against `str::split`.
+
+use split_every::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) {
+    const ITERATIONS: u32 = 20;
+    let mut total: Duration = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        black_box(run());
+        total += start.elapsed();
+    }
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let input: String = "the quick brown fox jumps over the lazy dog ".repeat(100_000);
+    // Patterns go through `black_box` so neither side searches for a constant.
+    bench("str::split(\" \")", || {
+        input.split(black_box(" ")).map(str::len).sum()
+    });
+    bench("split_every_n_times_ref(\" \", 1)", || {
+        input
+            .as_str()
+            .split_every_n_times_ref(black_box(" "), 1)
+            .map(str::len)
+            .sum()
+    });
+    bench("str::split(' ')", || {
+        input.split(black_box(' ')).map(str::len).sum()
+    });
+    bench("split_every_n_times_ref(' ', 1)", || {
+        input
+            .as_str()
+            .split_every_n_times_ref(black_box(' '), 1)
+            .map(str::len)
+            .sum()
+    });
+    bench("split_every_n_times_ref(\"fox\", 1)", || {
+        input
+            .as_str()
+            .split_every_n_times_ref(black_box("fox"), 1)
+            .map(str::len)
+            .sum()
+    });
+}
@@ -2,6 +2,9 @@
 //! This crate **helps you** split data for every `n` occurrences of a `pattern`.  
 //! It contains an exclusive `iterator`.
 //!
+//! The crate is `no_std` with `alloc` when the default `std` feature is disabled;
+//! `String`, `string::Drain`, and `OsStr` inputs and patterns need `std`.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -11,11 +14,11 @@
 //! //              [(0, 0)]
 //! //              [(0, 1), (0, 0)]
 //! //              [(0, 1)]
-//! let mut splitter: SplitEvery<&[(u8, u8)], &[(u8, u8)]> = [
+//! let mut splitter: SplitEvery<[(u8, u8); 9], [(u8, u8); 1]> = [
 //!     (0, 0), (0, 1), (0, 0),
 //!     (0, 0), (0, 0), (0, 1),
 //!     (0, 0), (0, 0), (0, 1),
-//! ].split_every_n_times(&[(0, 0)], 2);
+//! ].split_every_n_times([(0, 0)], 2);
 //! println!("{:?}", splitter.next().unwrap());
 //! println!("{:?}", splitter.next().unwrap());
 //! println!("{:?}", splitter.next().unwrap());
@@ -66,276 +69,9419 @@
 //! println!("{:?}", splitter.next().unwrap());
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::fmt::{Debug, DebugStruct, Formatter};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Index, Range};
+#[cfg(feature = "futures")]
+use core::pin::Pin;
+use core::str::{FromStr, Utf8Error};
+#[cfg(feature = "futures")]
+use core::task::{Context, Poll};
+#[cfg(feature = "futures")]
+use futures_core::{FusedStream, Stream};
+#[cfg(feature = "rayon")]
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::slice::ParallelSlice;
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
 /// Import all necessary traits and structs.
 pub mod prelude {
-    pub use crate::{SplitEvery, SplitEveryImpl, SplitEveryIterImpl};
+    #[cfg(feature = "std")]
+    pub use crate::SplitEveryRead;
+    pub use crate::{
+        split_every_n_times, AnyOf, ArrPattern, By, ByEq, ByteSet, CloneableFn, IgnoreAsciiCase,
+        RSplitEvery, SplitEvery, SplitEveryArrImpl, SplitEveryBoxed, SplitEveryBreak,
+        SplitEveryCharsImpl, SplitEveryCounted, SplitEveryDropInterior, SplitEveryEnumerate,
+        SplitEveryHaystack, SplitEveryImpl, SplitEveryInput, SplitEveryInto, SplitEveryIter,
+        SplitEveryIterImpl, SplitEveryMap, SplitEveryOptions, SplitEveryParse, SplitEveryPositions,
+        SplitEveryRanges, SplitEveryRef, SplitEveryRefImpl, SplitEveryRemainders, SplitEverySearch,
+        SplitEverySpec, SplitEveryTrigger, SplitEveryTry, SplitEveryTryImpl, SplitEveryUtf8,
+        SplitEveryWindows, SplitEveryWrapped, StrPattern, Strided,
+    };
+    #[cfg(feature = "rayon")]
+    pub use crate::{ParSplitEvery, SplitEveryParImpl};
+    #[cfg(feature = "futures")]
+    pub use crate::{SplitEveryStream, SplitEveryStreamImpl};
+}
+
+/// Splits `input` for every `n` occurrences of `pat`, without importing the extension traits.
+///
+/// This is the free-function form of `split_every_n_times`, for any input and pattern pair
+/// `SplitEvery` can iterate, such as `&str`, `Vec<T>`, and `&[T]`.
+///
+/// ```rust
+/// use split_every::split_every_n_times;
+///
+/// let words: Vec<String> = split_every_n_times("a b c d e", " ", 2).collect();
+/// assert_eq!(words, ["a b", "c d", "e"]);
+///
+/// let nums: Vec<Vec<u8>> = split_every_n_times(vec![1, 0, 2, 0, 3], [0], 1).collect();
+/// assert_eq!(nums, [vec![1], vec![2], vec![3]]);
+///
+/// let nums: Vec<Vec<u8>> = split_every_n_times(&[1, 0, 2, 0, 3][..], [0], 2).collect();
+/// assert_eq!(nums, [vec![1, 0, 2], vec![3]]);
+/// ```
+pub fn split_every_n_times<Input, Pattern>(
+    input: Input,
+    pat: Pattern,
+    n: usize,
+) -> SplitEvery<Input, Pattern>
+where
+    SplitEvery<Input, Pattern>: Iterator,
+{
+    SplitEvery::new(input, pat, n)
 }
 
 pub trait SplitEveryImpl: Sized {
-    fn split_every_n_times(self, pat: Self, n: usize) -> SplitEvery<Self, Self> {
-        SplitEvery {
+    fn split_every_n_times<Pattern>(self, pat: Pattern, n: usize) -> SplitEvery<Self, Pattern>
+    where
+        SplitEvery<Self, Pattern>: Iterator,
+    {
+        SplitEvery::new(self, pat, n)
+    }
+
+    /// Collects every chunk of `split_every_n_times` at once: a `Vec<String>` for strings
+    /// and a `Vec<Vec<T>>` for slices.
+    ///
+    /// The outer `Vec` is sized from the iterator's bounds rather than an occurrence count,
+    /// which would take a second pass over the input.
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// let groups: Vec<String> = "Oh hi there I don't really know what to say".group_every(" ", 3);
+    /// assert_eq!(groups, ["Oh hi there", "I don't really", "know what to", "say"]);
+    /// ```
+    fn group_every<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> Vec<<SplitEvery<Self, Pattern> as Iterator>::Item>
+    where
+        SplitEvery<Self, Pattern>: Iterator,
+    {
+        SplitEvery::new(self, pat, n).collect()
+    }
+
+    /// Like `split_every_n_times`, but yields the range of each chunk within the input
+    /// instead of a copy of it.
+    ///
+    /// Ranges are byte ranges for strings and index ranges for slices.
+    fn split_every_n_times_ranges<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryRanges<Self, Pattern>
+    where
+        SplitEveryRanges<Self, Pattern>: Iterator,
+    {
+        SplitEveryRanges(SplitEvery::new(self, pat, n))
+    }
+
+    /// Like `split_every_n_times`, but parses each string chunk with [`str::parse`], yielding
+    /// the parse results.
+    ///
+    /// Chunks are parsed while borrowed from the input, so none is copied, and the results
+    /// can be collected into a `Result<Vec<T>, T::Err>`.
+    fn split_every_n_times_parse<T: FromStr, Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryParse<Self, Pattern, T>
+    where
+        SplitEveryParse<Self, Pattern, T>: Iterator<Item = Result<T, T::Err>>,
+    {
+        SplitEvery::new(self, pat, n).map_chunks(str::parse)
+    }
+
+    /// Like `split_every_n_times`, but yields each string chunk as a `Box<str>`.
+    ///
+    /// A `Box<str>` has no spare capacity to track, so retaining many small chunks takes
+    /// one word less per chunk than retaining `String`s.
+    fn split_every_n_times_boxed<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryBoxed<Self, Pattern>
+    where
+        SplitEveryBoxed<Self, Pattern>: Iterator<Item = Box<str>>,
+    {
+        SplitEvery::new(self, pat, n).map_chunks(|chunk| Box::from(chunk))
+    }
+
+    /// Like `split_every_n_times`, but also yields how many patterns each chunk consumed.
+    ///
+    /// This is `n` for every chunk terminated by its `n`th pattern, and fewer for a
+    /// final chunk that ran out of input first.
+    fn split_every_n_times_counted<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryCounted<Self, Pattern>
+    where
+        SplitEveryCounted<Self, Pattern>: Iterator,
+    {
+        SplitEveryCounted(SplitEvery::new(self, pat, n))
+    }
+
+    /// Like `split_every_n_times`, but decodes each byte chunk as UTF-8.
+    ///
+    /// A chunk that is not valid UTF-8 is yielded as an `Err` and iteration continues with
+    /// the next chunk. Supported for `&[u8]` and `Vec<u8>`.
+    fn split_every_n_times_utf8<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryUtf8<Self, Pattern>
+    where
+        SplitEveryUtf8<Self, Pattern>: Iterator,
+    {
+        SplitEveryUtf8(SplitEvery::new(self, pat, n))
+    }
+
+    /// Splits text every `n` words, breaking earlier where a chunk would exceed
+    /// `max_width` chars, for wrapping text to a terminal.
+    ///
+    /// Words are separated by runs of whitespace, as in [`str::split_whitespace`]. Each chunk
+    /// spans from its first word to its last, keeping the whitespace between them, and
+    /// whitespace between chunks is dropped. A word longer than `max_width` is never broken
+    /// up but yielded as a chunk of its own. An `n` of `0` only breaks on width.
+    /// Supported for `&str`.
+    fn split_every_n_words_wrapped(self, n: usize, max_width: usize) -> SplitEveryWrapped<Self>
+    where
+        SplitEveryWrapped<Self>: Iterator,
+    {
+        SplitEveryWrapped {
+            input: self,
+            n,
+            max_width,
+            ind: 0,
+        }
+    }
+
+    /// Like `split_every_n_times`, but also splits at once at every occurrence of `brk`,
+    /// whatever the count of `pat` so far, then starts counting again.
+    ///
+    /// Both delimiters are dropped, and each chunk is paired with the
+    /// [`SplitEveryTrigger`] that ended it. Where `pat` and `brk` match at the same
+    /// position, `brk` wins. An `n` of `0` only splits at `brk`. Supported for `&str`.
+    fn split_every_n_with_break<Pattern, Break>(
+        self,
+        pat: Pattern,
+        n: usize,
+        brk: Break,
+    ) -> SplitEveryBreak<Self, Pattern, Break>
+    where
+        SplitEveryBreak<Self, Pattern, Break>: Iterator,
+    {
+        SplitEveryBreak {
             input: self,
             pat,
             n,
+            brk,
             ind: 0,
         }
     }
+
+    /// Like `split_every_n_times`, but counts occurrences from the end of the input, like
+    /// [`str::rsplitn`].
+    ///
+    /// This changes the grouping rather than just the direction: `"a b c d e"` split every
+    /// 2 `" "` yields `"d e"`, `"b c"`, and `"a"`, whereas reversing `split_every_n_times`
+    /// yields `"e"`, `"c d"`, and `"a b"`. Supported for `&str` and `&[T]`.
+    fn rsplit_every_n_times<Pattern>(self, pat: Pattern, n: usize) -> RSplitEvery<Self, Pattern>
+    where
+        RSplitEvery<Self, Pattern>: Iterator,
+    {
+        RSplitEvery(SplitEvery::new(self, pat, n))
+    }
+
+    /// Shorthand for `split_every_n_times(pat, 1)`.
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// let mut splitter: SplitEvery<&str, &str> = "a.b.c".split_every_once(".");
+    /// assert_eq!(splitter.next().unwrap(), "a");
+    /// assert_eq!(splitter.next().unwrap(), "b");
+    /// assert_eq!(splitter.next().unwrap(), "c");
+    /// assert_eq!(splitter.next(), None);
+    /// ```
+    fn split_every_once<Pattern>(self, pat: Pattern) -> SplitEvery<Self, Pattern>
+    where
+        SplitEvery<Self, Pattern>: Iterator,
+    {
+        SplitEvery::new(self, pat, 1)
+    }
+
+    /// Like `split_every_n_times`, but input ending on the `n`th pattern yields a final empty
+    /// chunk, see [`SplitEvery::keep_trailing_empty`].
+    fn split_every_n_times_keep_last<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEvery<Self, Pattern>
+    where
+        SplitEvery<Self, Pattern>: Iterator,
+    {
+        SplitEvery::new(self, pat, n).keep_trailing_empty(true)
+    }
+
+    /// Like `split_every_n_times`, but keeps the `n`th pattern at the end of each chunk.
+    fn split_every_n_times_inclusive<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEvery<Self, Pattern>
+    where
+        SplitEvery<Self, Pattern>: Iterator,
+    {
+        let mut split_every: SplitEvery<Self, Pattern> = SplitEvery::new(self, pat, n);
+        split_every.inclusive = true;
+        split_every
+    }
+
+    /// Like `split_every_n_times`, but counts an occurrence of any pattern in `pats`.
+    ///
+    /// When several patterns match at the same position, the longest one wins.
+    fn split_every_n_times_any<'p>(
+        self,
+        pats: &'p [Self],
+        n: usize,
+    ) -> SplitEvery<Self, AnyOf<'p, Self>>
+    where
+        SplitEvery<Self, AnyOf<'p, Self>>: Iterator,
+    {
+        SplitEvery::new(self, AnyOf(pats), n)
+    }
+
+    /// Like `split_every_n_times`, but counts any single byte in `set` as an occurrence,
+    /// see [`ByteSet`].
+    fn split_every_n_times_byte_set(self, set: &[u8], n: usize) -> SplitEvery<Self, ByteSet>
+    where
+        SplitEvery<Self, ByteSet>: Iterator,
+    {
+        SplitEvery::new(self, ByteSet::new(set), n)
+    }
+
+    /// Like `split_every_n_times`, but matches `pat` ignoring ASCII case.
+    fn split_every_n_times_ignore_ascii_case<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEvery<Self, IgnoreAsciiCase<Pattern>>
+    where
+        SplitEvery<Self, IgnoreAsciiCase<Pattern>>: Iterator,
+    {
+        SplitEvery::new(self, IgnoreAsciiCase(pat), n)
+    }
+
+    /// Like `split_every_n_times`, but counts each match of `re` as an occurrence.
+    ///
+    /// Matches are found with `regex`'s leftmost-first, non-overlapping semantics.
+    /// Empty matches never count, so a regex like `a*` only splits on runs of `a`.
+    #[cfg(feature = "regex")]
+    fn split_every_n_times_regex<'a>(
+        self,
+        re: &'a regex::Regex,
+        n: usize,
+    ) -> SplitEvery<Self, &'a regex::Regex>
+    where
+        SplitEvery<Self, &'a regex::Regex>: Iterator,
+    {
+        SplitEvery::new(self, re, n)
+    }
 }
 
 impl SplitEveryImpl for &str {}
+#[cfg(feature = "std")]
 impl SplitEveryImpl for String {}
-impl SplitEveryImpl for std::string::Drain<'_> {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for Cow<'_, str> {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for &String {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for &mut String {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for alloc::string::Drain<'_> {}
 impl<T: Clone + PartialEq> SplitEveryImpl for Vec<T> {}
+impl<T: PartialEq> SplitEveryImpl for alloc::vec::Drain<'_, T> {}
+impl<T: Clone + PartialEq> SplitEveryImpl for VecDeque<T> {}
 impl<T: Clone + PartialEq> SplitEveryImpl for &[T] {}
+impl<T: Clone + PartialEq, const N: usize> SplitEveryImpl for [T; N] {}
+#[cfg(feature = "bytes")]
+impl SplitEveryImpl for bytes::Bytes {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for &OsStr {}
+#[cfg(feature = "std")]
+impl SplitEveryImpl for OsString {}
+
+/// Splits borrowed input into chunks that borrow from it instead of copying.
+///
+/// A `String` or `Vec<T>` reaches the `&str` or `&[T]` implementation through auto-deref,
+/// so `my_string.split_every_n_times_ref(",", 1)` borrows `my_string`, leaving it usable
+/// once the chunks are dropped.
+pub trait SplitEveryRefImpl: Sized {
+    fn split_every_n_times_ref<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryRef<Self, Pattern>
+    where
+        SplitEveryRef<Self, Pattern>: Iterator,
+    {
+        SplitEveryRef(SplitEvery::new(self, pat, n))
+    }
+
+    /// Like `split_every_n_times_ref`, but keeps the `n`th pattern at the end of each chunk.
+    fn split_every_n_times_ref_inclusive<Pattern>(
+        self,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEveryRef<Self, Pattern>
+    where
+        SplitEveryRef<Self, Pattern>: Iterator,
+    {
+        let mut split_every: SplitEvery<Self, Pattern> = SplitEvery::new(self, pat, n);
+        split_every.inclusive = true;
+        SplitEveryRef(split_every)
+    }
+}
+
+impl SplitEveryRefImpl for &str {}
+impl<T> SplitEveryRefImpl for &[T] {}
+/// Chunks of a `&mut [T]` are yielded as disjoint `&mut [T]`s, like [`slice::split_mut`].
+impl<T> SplitEveryRefImpl for &mut [T] {}
+
+pub trait SplitEveryArrImpl<T>: Sized {
+    /// Like `split_every_n_times`, but counts an occurrence whenever `pred` returns `true`.
+    fn split_every_n_times_by<F: FnMut(&T) -> bool>(
+        self,
+        pred: F,
+        n: usize,
+    ) -> SplitEvery<Self, By<F>> {
+        SplitEvery::new(self, By(pred), n)
+    }
+
+    /// Like `split_every_n_times`, but elements match `pat` whenever `eq` returns `true`
+    /// for them, such as floats within an epsilon of each other.
+    ///
+    /// `eq` is called with an input element first and a pattern element second.
+    fn split_every_n_times_by_eq<Pattern: AsRef<[T]>, F: FnMut(&T, &T) -> bool>(
+        self,
+        pat: Pattern,
+        n: usize,
+        eq: F,
+    ) -> SplitEvery<Self, ByEq<Pattern, F>> {
+        SplitEvery::new(self, ByEq(pat, eq), n)
+    }
+
+    /// Like `split_every_n_times`, but the pattern is the single element `elem`.
+    fn split_every_n_times_elem(self, elem: T, n: usize) -> SplitEvery<Self, [T; 1]>
+    where
+        T: PartialEq,
+    {
+        SplitEvery::new(self, [elem], n)
+    }
+
+    /// Like `split_every_n_times_elem`, but borrows `elem` instead of taking it.
+    ///
+    /// Single-element patterns are compared element by element rather than through
+    /// `windows`, so this is as fast as passing `[elem]`.
+    fn split_every_n_times_elem_ref(self, elem: &T, n: usize) -> SplitEvery<Self, &[T]>
+    where
+        T: PartialEq,
+    {
+        SplitEvery::new(self, core::slice::from_ref(elem), n)
+    }
+}
 
-pub trait SplitEveryIterImpl<'a, T: Clone + PartialEq>: Iterator<Item = T> + Sized + 'a {
+impl<T: Clone> SplitEveryArrImpl<T> for Vec<T> {}
+impl<T: Clone> SplitEveryArrImpl<T> for &[T] {}
+impl<T: Clone> SplitEveryArrImpl<T> for VecDeque<T> {}
+impl<T: Clone, const N: usize> SplitEveryArrImpl<T> for [T; N] {}
+
+/// Splits the items of any iterator, boxing it as a closure input.
+///
+/// The box only lives as long as the iterator, so iterators borrowing local data work too.
+/// When naming the resulting type, spell out the lifetime as in
+/// `SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T>`, since a bare `Box<dyn FnMut() -> _>`
+/// means `'static`.
+///
+/// Items are moved into chunks, never cloned, so an iterator of references such as
+/// `slice.iter()` yields `Vec<&T>` chunks and takes a `&T` pattern, leaving `T` untouched.
+pub trait SplitEveryIterImpl<'a, T>: Iterator<Item = T> + Sized + 'a {
     fn split_every_n_times(
         mut self,
         pat: T,
         n: usize,
-    ) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T> {
+    ) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T>
+    where
+        T: PartialEq,
+    {
         SplitEvery::n_times_from_fn(Box::new(move || self.next()), pat, n)
     }
+
+    /// Shorthand for `split_every_n_times(pat, 1)`.
+    fn split_every_once(self, pat: T) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T>
+    where
+        T: PartialEq,
+    {
+        SplitEveryIterImpl::split_every_n_times(self, pat, 1)
+    }
+
+    /// Like `split_every_n_times`, but keeps the `n`th pattern at the end of each chunk.
+    fn split_every_n_times_inclusive(
+        mut self,
+        pat: T,
+        n: usize,
+    ) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T>
+    where
+        T: PartialEq,
+    {
+        let mut split_every: SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, T> =
+            SplitEvery::n_times_from_fn(Box::new(move || self.next()), pat, n);
+        split_every.inclusive = true;
+        split_every
+    }
+
+    /// Like `split_every_n_times`, but counts an occurrence whenever `pred` returns `true`.
+    fn split_every_n_times_by<F: FnMut(&T) -> bool>(
+        mut self,
+        pred: F,
+        n: usize,
+    ) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, By<F>> {
+        SplitEvery::new(Box::new(move || self.next()), By(pred), n)
+    }
+
+    /// Like `split_every_n_times`, but only counts an occurrence at positions that are a
+    /// multiple of `stride`, counted from the first item of the iterator.
+    ///
+    /// This keeps fixed-width records of `stride` items whole even when a field happens to
+    /// equal `pat`. A `stride` of 0 or 1 counts every occurrence.
+    fn split_every_n_times_strided(
+        mut self,
+        pat: T,
+        n: usize,
+        stride: usize,
+    ) -> SplitEvery<Box<dyn FnMut() -> Option<T> + 'a>, Strided<T>>
+    where
+        T: PartialEq,
+    {
+        SplitEvery::new(
+            Box::new(move || self.next()),
+            Strided {
+                pat,
+                stride: stride.max(1),
+                pos: 0,
+            },
+            n,
+        )
+    }
+
+    /// Like `split_every_n_times`, but stores the iterator directly instead of boxing it,
+    /// avoiding an allocation and a dynamic call per element.
+    fn split_every_n_times_iter(self, pat: T, n: usize) -> SplitEveryIter<Self, T>
+    where
+        T: PartialEq,
+    {
+        SplitEveryIter(SplitEvery::new(self, pat, n))
+    }
+}
+
+impl<'a, T, U: Iterator<Item = T> + Sized + 'a> SplitEveryIterImpl<'a, T> for U {}
+
+/// Splits the chars of a char iterator into `String` chunks.
+pub trait SplitEveryCharsImpl<'a>: Iterator<Item = char> + Sized + 'a {
+    /// Like `split_every_n_times`, but collects each chunk into a `String` instead of a
+    /// `Vec<char>`.
+    fn split_every_n_chars(
+        mut self,
+        pat: char,
+        n: usize,
+    ) -> SplitEveryInto<Box<dyn FnMut() -> Option<char> + 'a>, char, String> {
+        SplitEvery::n_times_from_fn(Box::new(move || self.next()) as Box<_>, pat, n)
+            .collect_chunks_into()
+    }
 }
 
-impl<'a, T: Clone + PartialEq, U: Iterator<Item = T> + Sized + 'a> SplitEveryIterImpl<'a, T> for U {}
+impl<'a, U: Iterator<Item = char> + Sized + 'a> SplitEveryCharsImpl<'a> for U {}
 
+/// Splits the `Ok` values of a fallible iterator.
+pub trait SplitEveryTryImpl<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Like `split_every_n_times`, but yields the first `Err` in place of the chunk it
+    /// occurred in, then stops, like collecting into a `Result`.
+    ///
+    /// Chunks completed before the error are still yielded.
+    fn try_split_every_n_times(self, pat: T, n: usize) -> SplitEveryTry<Self, T>
+    where
+        T: PartialEq,
+    {
+        SplitEveryTry(SplitEvery::new(self, pat, n))
+    }
+}
+
+impl<T, E, U: Iterator<Item = Result<T, E>> + Sized> SplitEveryTryImpl<T, E> for U {}
+
+/// An iterator splitting its input for every `n` occurrences of a pattern.
+///
+/// Like [`str::split_terminator`], input that starts with or contains back-to-back patterns
+/// yields empty chunks, but input ending with the `n`th pattern yields no trailing empty
+/// chunk. `"a b "` split every `" "` yields `"a"` and `"b"`, while `" a  b"` yields `""`,
+/// `"a"`, `""`, and `"b"`. Empty input yields nothing. This holds for every input kind.
+///
+/// An empty pattern never matches, so the remaining input is yielded as a single chunk.
+/// Likewise, an `n` of `0` never splits: the whole input is yielded once, then `None`.
+/// This holds for every input kind and from either end, and `split_every_n_times_counted`
+/// reports that chunk as having consumed no patterns.
+///
+/// Only the `n`th pattern divides chunks, so for `n` above 1 the patterns before it stay
+/// inside the chunk: `"a,b,c,d"` split every 2 `","` yields `"a,b"` and `"c,d"`. This holds
+/// for string, slice, and closure inputs alike, and
+/// [`drop_interior_delimiters`](SplitEvery::drop_interior_delimiters) removes them instead.
+///
+/// In inclusive mode the `n`th pattern is kept at the end of the chunk it terminates,
+/// so no chunk is ever empty.
+///
+/// With the `serde` feature, data-backed iterators can be serialized mid-way and
+/// resumed later. String and slice iterators are deserialized only if their position lies
+/// within the input, on a char boundary for strings.
+///
+/// The iterator holds nothing but its input, pattern, and plain counters, so it is `Send`
+/// and `Sync` whenever both its input and pattern are. Data-backed iterators such as
+/// `SplitEvery<String, String>` or `SplitEvery<Vec<u8>, [u8; 1]>` can therefore be moved to
+/// another thread. Iterators built from an iterator or closure are only as `Send` as that
+/// closure, and the `Box<dyn FnMut>` returned by the `SplitEveryIterImpl` methods is neither.
+///
+/// Two iterators are equal when they split the same input by the same pattern every `n`
+/// times with the same options, and have consumed it up to the same position from both
+/// ends. Only data-backed iterators can be compared; closures have no notion of equality.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SplitEvery<Input, Pattern> {
     input: Input,
     pat: Pattern,
     n: usize,
     ind: usize,
+    /// Number of bytes / elements already consumed from the back of `input`.
+    back: usize,
+    finished: bool,
+    allow_trailing_empty: bool,
+    /// Whether the occurrences left are one short of a multiple of `n`, so that `next_back`
+    /// can find the start of the last chunk by counting `n` of them from the end.
+    back_aligned: bool,
+    /// Whether input ending on the `n`th pattern yields a final empty chunk.
+    keep_trailing_empty: bool,
+    inclusive: bool,
+    /// Keeps every pattern, on top of the `n`th one that `inclusive` keeps.
+    lossless: bool,
+    overlapping: bool,
+    skip_empty: bool,
+    trim: bool,
+    require_pattern: bool,
+    /// Chunks shorter than this are merged with the chunks following them.
+    min_chunk_len: usize,
+    /// Initial capacity of each chunk collected from an iterator.
+    chunk_capacity: usize,
+    /// Number of chunks left to yield before the remaining input is yielded whole.
+    max_splits: Option<usize>,
+    /// The chunk limit as configured, restored by `reset`.
+    split_limit: Option<usize>,
 }
 
-impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> SplitEvery<Input, Pattern> {
-    pub fn n_times_from_fn(input: Input, pat: Pattern, n: usize) -> SplitEvery<Input, Pattern> {
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SplitEvery<&str, &str>>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<SplitEvery<String, String>>();
+    assert_send_sync::<SplitEvery<Vec<u8>, Vec<u8>>>();
+    assert_send_sync::<SplitEvery<&[u8], [u8; 1]>>();
+    assert_send_sync::<SplitEveryRef<&str, char>>();
+    assert_send_sync::<SplitEveryRef<&mut [u8], u8>>();
+};
+
+/// The fields of a serialized [`SplitEvery`], checked before they become one.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "SplitEvery")]
+struct SplitEveryState<Input, Pattern> {
+    input: Input,
+    pat: Pattern,
+    n: usize,
+    ind: usize,
+    back: usize,
+    finished: bool,
+    allow_trailing_empty: bool,
+    back_aligned: bool,
+    keep_trailing_empty: bool,
+    inclusive: bool,
+    lossless: bool,
+    overlapping: bool,
+    skip_empty: bool,
+    trim: bool,
+    require_pattern: bool,
+    min_chunk_len: usize,
+    chunk_capacity: usize,
+    max_splits: Option<usize>,
+    split_limit: Option<usize>,
+}
+
+/// Resumes a serialized splitter, rejecting any position iteration could not have reached,
+/// since the chunks are sliced from the input without bounds checks.
+#[cfg(feature = "serde")]
+impl<'de, Input, Pattern> serde::Deserialize<'de> for SplitEvery<Input, Pattern>
+where
+    Input: SplitEveryInput + serde::Deserialize<'de>,
+    Pattern: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state: SplitEveryState<Input, Pattern> = SplitEveryState::deserialize(deserializer)?;
+        let haystack: &Input::Haystack = state.input.as_ref();
+        let Some(end) = haystack.len().checked_sub(state.back) else {
+            return Err(serde::de::Error::custom(
+                "`back` is past the start of the input",
+            ));
+        };
+        if state.ind > end {
+            return Err(serde::de::Error::custom("`ind` is past `back`"));
+        }
+        if !haystack.is_boundary(state.ind) || !haystack.is_boundary(end) {
+            return Err(serde::de::Error::custom(
+                "position is not on a char boundary",
+            ));
+        }
+        if state.back_aligned && state.back == 0 {
+            return Err(serde::de::Error::custom(
+                "`back_aligned` is set before any chunk was taken from the back",
+            ));
+        }
+        match (state.max_splits, state.split_limit) {
+            (None, None) => {}
+            (Some(max_splits), Some(split_limit)) if max_splits <= split_limit => {}
+            _ => {
+                return Err(serde::de::Error::custom(
+                    "`max_splits` exceeds `split_limit`",
+                ))
+            }
+        }
+        Ok(SplitEvery {
+            input: state.input,
+            pat: state.pat,
+            n: state.n,
+            ind: state.ind,
+            back: state.back,
+            finished: state.finished,
+            allow_trailing_empty: state.allow_trailing_empty,
+            back_aligned: state.back_aligned,
+            keep_trailing_empty: state.keep_trailing_empty,
+            inclusive: state.inclusive,
+            lossless: state.lossless,
+            overlapping: state.overlapping,
+            skip_empty: state.skip_empty,
+            trim: state.trim,
+            require_pattern: state.require_pattern,
+            min_chunk_len: state.min_chunk_len,
+            chunk_capacity: state.chunk_capacity,
+            max_splits: state.max_splits,
+            split_limit: state.split_limit,
+        })
+    }
+}
+
+impl<Input, Pattern> SplitEvery<Input, Pattern> {
+    fn new(input: Input, pat: Pattern, n: usize) -> Self {
         SplitEvery {
             input,
             pat,
             n,
             ind: 0,
+            back: 0,
+            finished: false,
+            allow_trailing_empty: false,
+            back_aligned: false,
+            keep_trailing_empty: false,
+            inclusive: false,
+            lossless: false,
+            overlapping: false,
+            skip_empty: false,
+            trim: false,
+            require_pattern: false,
+            min_chunk_len: 0,
+            chunk_capacity: 0,
+            max_splits: None,
+            split_limit: None,
         }
     }
-}
 
-impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> Iterator
-    for SplitEvery<Input, Pattern>
-{
-    type Item = Vec<Pattern>;
+    /// Returns the offset where each chunk of `split_every_n_times` begins, without
+    /// building the chunks.
+    ///
+    /// Offsets are byte offsets for strings and indices for slices, and the last chunk
+    /// runs to the end of the input. Supported wherever
+    /// [`split_every_n_times_ranges`](SplitEveryImpl::split_every_n_times_ranges) is.
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// assert_eq!(SplitEvery::scan_offsets("a,b,c,d,e", ",", 2), [0, 4, 8]);
+    /// ```
+    pub fn scan_offsets(input: Input, pat: Pattern, n: usize) -> Vec<usize>
+    where
+        SplitEveryRanges<Input, Pattern>: Iterator<Item = Range<usize>>,
+    {
+        SplitEveryRanges(SplitEvery::new(input, pat, n))
+            .map(|range| range.start)
+            .collect()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.n == 0 {
-            let out: Vec<Pattern> = std::iter::repeat_with(|| (self.input)())
-                .take_while(Option::is_some)
-                .flatten()
-                .collect();
-            if out.is_empty() {
-                return None;
-            }
-            return Some(out);
-        }
-        let mut out: Vec<Pattern> = Vec::with_capacity(5);
-        'main: for ind in 0..self.n {
-            while let Some(val) = (self.input)() {
-                if val == self.pat {
-                    if ind == unsafe { self.n.unchecked_sub(1) } {
-                        break 'main;
-                    }
-                    out.push(val);
-                    continue 'main;
-                }
-                out.push(val);
-            }
-        }
-        if out.is_empty() {
-            return None;
-        }
-        Some(out)
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(mut self, max_splits: usize) -> Self {
+        self.max_splits = Some(max_splits);
+        self.split_limit = Some(max_splits);
+        self
     }
-}
 
-impl<Pattern: AsRef<str>> Iterator for SplitEvery<&str, Pattern> {
-    type Item = String;
+    /// Counts overlapping occurrences of the pattern, like `"aa"` twice in `"aaa"`.
+    ///
+    /// Since overlapping occurrences have no single dividing point, each chunk keeps the
+    /// `n`th pattern at its end, as in inclusive mode, and the next chunk starts right after
+    /// it. Occurrences are never counted across chunk boundaries.
+    pub fn overlapping(mut self, overlapping: bool) -> Self {
+        self.overlapping = overlapping;
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        split_every_next_str_helper(self)
+    /// Keeps every pattern in the chunks, so joining them with no separator reproduces the
+    /// input exactly.
+    ///
+    /// Each `n`th pattern ends the chunk it terminates, as with `split_every_n_times_inclusive`,
+    /// and the other patterns stay inside their chunk. No pattern is repeated at the start of
+    /// the next chunk, as that would duplicate it. `"a,b,c"` split every 1 `","` yields
+    /// `"a,"`, `"b,"`, and `"c"`, and `"a,b,"` yields `"a,"` and `"b,"`. Since no chunk is
+    /// ever empty, `skip_empty` drops nothing.
+    ///
+    /// This is tracked apart from inclusive mode, so `lossless(false)` leaves a splitter from
+    /// `split_every_n_times_inclusive` inclusive.
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = lossless;
+        self
     }
-}
 
-impl<Pattern: AsRef<str>> Iterator for SplitEvery<String, Pattern> {
-    type Item = String;
+    /// Drops empty chunks instead of yielding them.
+    ///
+    /// The patterns terminating a dropped chunk still count towards `n`, so `"a,,b,c"` split
+    /// every 2 `","` yields `"a,"` then `"b,c"` with or without this option, while split every
+    /// 1 it yields `"a"`, `"b"`, and `"c"`. Dropped chunks do not count against `max_splits`.
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self {
+        self.skip_empty = skip_empty;
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        split_every_next_str_helper(self)
+    /// Yields a final empty chunk when the input ends on the `n`th pattern, like [`str::split`]
+    /// rather than [`str::split_terminator`].
+    ///
+    /// This guarantees a chunk for every group: `"a,b,"` split every 1 `","` yields `"a"`,
+    /// `"b"`, and `""`, and empty input yields a single empty chunk. It has no effect with
+    /// [`lossless`](SplitEvery::lossless) or [`overlapping`](SplitEvery::overlapping), whose
+    /// chunks already end with the pattern, and [`skip_empty`](SplitEvery::skip_empty) still
+    /// drops the empty chunk.
+    pub fn keep_trailing_empty(mut self, keep_trailing_empty: bool) -> Self {
+        self.keep_trailing_empty = keep_trailing_empty;
+        self
     }
-}
 
-impl<Pattern: AsRef<str>> Iterator for SplitEvery<std::string::Drain<'_>, Pattern> {
-    type Item = String;
+    /// Trims whitespace off both ends of each string chunk, like [`str::trim`].
+    ///
+    /// Trimming only changes what is yielded, never where the input is split: patterns are
+    /// counted in the untrimmed input, so `" a , b , c "` split every 2 `","` yields `"a , b"`
+    /// and `"c"`. With [`skip_empty`](SplitEvery::skip_empty), chunks that trim down to
+    /// nothing are dropped too.
+    pub fn trim_chunks(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        split_every_next_str_helper(self)
+    /// Yields nothing for input containing no occurrence of the pattern.
+    ///
+    /// By default such input is yielded whole as a single chunk, just like input with fewer
+    /// than `n` occurrences, so it cannot be told apart from a valid single group. With this
+    /// option, `"abc"` split every `","` yields nothing, while `"abc,"` still yields `"abc"`
+    /// and `"a,b"` split every 3 `","` still yields `"a,b"`. An empty pattern never matches,
+    /// so it yields nothing. Supported for string and slice inputs.
+    pub fn require_pattern(mut self, require_pattern: bool) -> Self {
+        self.require_pattern = require_pattern;
+        self
     }
-}
 
-fn split_every_next_str_helper<Input: AsRef<str>, Pattern: AsRef<str>>(
-    split_every: &mut SplitEvery<Input, Pattern>,
-) -> Option<String> {
-    let input: &str = split_every.input.as_ref();
-    if split_every.ind == input.len() {
-        return None;
+    /// Merges chunks shorter than `min_chunk_len` bytes / elements with the chunks following
+    /// them, until the merged chunk is long enough.
+    ///
+    /// A merged chunk is the contiguous span of input it covers, so the patterns between its
+    /// parts are kept: `"a,b,cd,e"` split every 1 `","` with a minimum of 2 yields `"a,b"`,
+    /// `"cd"`, and `"e"`. As the last chunk has nothing left to merge with, it may still be
+    /// shorter. Merged chunks count as one against `max_splits`, and lengths are measured
+    /// after [`trim_chunks`](SplitEvery::trim_chunks). Iterating from the back yields the same
+    /// chunks, at the cost of a scan from the front per chunk. Supported for string and slice
+    /// inputs.
+    pub fn min_chunk_len(mut self, min_chunk_len: usize) -> Self {
+        self.min_chunk_len = min_chunk_len;
+        self
     }
-    let pat: &str = split_every.pat.as_ref();
-    let iter_haystack: &str = unsafe { input.get_unchecked(split_every.ind..) };
-    let mut len: usize = 0;
-    for ind in 0..split_every.n {
-        let haystack: &str = unsafe { iter_haystack.get_unchecked(len..) };
-        if let Some(byte_ind) = haystack.find(pat) {
-            len = unsafe { len.unchecked_add(byte_ind).unchecked_add(pat.len()) };
-            continue;
+
+    /// Reserves room for `chunk_capacity` elements in each chunk collected from an iterator.
+    ///
+    /// Without it, chunks start empty and grow as elements are pushed, reallocating
+    /// repeatedly for large chunks. Chunks of slice inputs are always allocated at their
+    /// exact size, so they are unaffected.
+    pub fn chunk_capacity(mut self, chunk_capacity: usize) -> Self {
+        self.chunk_capacity = chunk_capacity;
+        self
+    }
+
+    /// Whether the `n`th pattern is kept at the end of each chunk.
+    fn keeps_pattern(&self) -> bool {
+        self.inclusive || self.lossless || self.overlapping
+    }
+
+    /// Whether an empty chunk is yielded once the input runs out.
+    fn allows_trailing_empty(&self) -> bool {
+        self.allow_trailing_empty || (self.keep_trailing_empty && !self.keeps_pattern())
+    }
+
+    /// Counts the chunk about to be yielded from the front against `max_splits`.
+    ///
+    /// Returns `false` if the remaining input has to be yielded as a single chunk.
+    fn take_chunk(&mut self) -> bool {
+        if let Some(max_splits) = self.max_splits {
+            self.max_splits = Some(max_splits.saturating_sub(1));
         }
-        if ind == 0 {
-            split_every.ind = input.len();
-            return Some(haystack.to_string());
+        self.n != 0 && self.max_splits != Some(0)
+    }
+
+    /// Consumes the iterator, returning the original input, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        (self.input, self.pat, self.n)
+    }
+
+    /// Collects each chunk into `Chunk` instead of a [`Vec`].
+    ///
+    /// Supported for slice and closure inputs.
+    pub fn collect_chunks_into<Chunk>(self) -> SplitEveryInto<Input, Pattern, Chunk> {
+        SplitEveryInto(self, PhantomData)
+    }
+
+    /// Pairs each chunk with the total number of patterns consumed so far, including
+    /// those consumed by the chunk itself.
+    ///
+    /// Supported for string and slice inputs.
+    pub fn enumerate_chunks(self) -> SplitEveryEnumerate<Input, Pattern> {
+        SplitEveryEnumerate {
+            inner: SplitEveryCounted(self),
+            total: 0,
         }
-        break;
     }
-    split_every.ind = unsafe { split_every.ind.unchecked_add(len) };
-    Some(unsafe { iter_haystack.get_unchecked(..len.unchecked_sub(pat.len())) }.to_string())
-}
 
-impl<T: Clone + PartialEq> Iterator for SplitEvery<Vec<T>, Vec<T>> {
-    type Item = Vec<T>;
+    /// Removes the patterns left inside each chunk, so `"a,b,c,d"` split every 2 `","`
+    /// yields `"ab"` and `"cd"`.
+    ///
+    /// Every occurrence found by searching the chunk again is removed, including the `n`th
+    /// pattern in inclusive mode. Supported for string, slice, and closure inputs.
+    pub fn drop_interior_delimiters(self) -> SplitEveryDropInterior<Input, Pattern> {
+        SplitEveryDropInterior(self)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let (ind, out): (usize, Option<Vec<T>>) =
-            split_every_next_arr_helper(self, &self.input, &self.pat);
-        self.ind = ind;
-        out
+    /// Starts each chunk over the last `overlap` segments of the previous one, where a
+    /// segment is the input between two patterns.
+    ///
+    /// `"a b c d"` split every 2 `" "` with an overlap of 1 yields `"a b"`, `"b c"`, and
+    /// `"c d"`. The pattern ending a chunk is still dropped from it, and the overlapping
+    /// segments bring the patterns between them along. Iteration stops once a chunk reaches
+    /// the end of the input. An `overlap` of `n` or more is treated as `n - 1`, so every
+    /// chunk still moves forward. Other options are ignored. Supported for `&str` and
+    /// `&[T]`.
+    pub fn window_overlap(self, overlap: usize) -> SplitEveryWindows<Input, Pattern> {
+        SplitEveryWindows {
+            inner: self,
+            overlap,
+        }
     }
-}
 
-impl<T: Clone + PartialEq> Iterator for SplitEvery<&[T], &[T]> {
-    type Item = Vec<T>;
+    /// Pairs each chunk with its range within the input, found in the same pass.
+    ///
+    /// Ranges are byte ranges for strings and index ranges for slices, as with
+    /// `split_every_n_times_ranges`. Supported for string and slice inputs.
+    pub fn chunks_and_positions(self) -> SplitEveryPositions<Input, Pattern> {
+        SplitEveryPositions(self)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let (ind, out): (usize, Option<Vec<T>>) =
-            split_every_next_arr_helper(self, self.input, self.pat);
-        self.ind = ind;
-        out
+    /// Applies `f` to each chunk while it is still borrowed from the input.
+    ///
+    /// Unlike `map`, no owned chunk is allocated first, so `map_chunks(str::trim)` only
+    /// borrows. Supported for string and slice inputs.
+    ///
+    /// To reduce chunks without copying them, use
+    /// [`fold_chunks`](SplitEvery::fold_chunks) instead of `fold`.
+    pub fn map_chunks<F>(self, f: F) -> SplitEveryMap<Input, Pattern, F> {
+        SplitEveryMap { inner: self, f }
     }
-}
 
-fn split_every_next_arr_helper<T, U: Clone + PartialEq>(
-    split_every: &SplitEvery<T, T>,
-    input: &[U],
-    pat: &[U],
-) -> (usize, Option<Vec<U>>) {
-    if split_every.ind == input.len() {
-        return (split_every.ind, None);
+    /// Restarts iteration from the beginning of the input.
+    fn rewind(&mut self) {
+        self.ind = 0;
+        self.back = 0;
+        self.finished = false;
+        self.allow_trailing_empty = false;
+        self.back_aligned = false;
+        self.max_splits = self.split_limit;
     }
-    let iter_haystack: &[U] = unsafe { input.get_unchecked(split_every.ind..) };
-    let mut len: usize = 0;
-    for ind in 0..split_every.n {
-        if len == iter_haystack.len() {
-            break;
+
+    /// Copies the iteration state onto a different input and pattern.
+    fn with_parts<ViewInput, ViewPattern>(
+        &self,
+        input: ViewInput,
+        pat: ViewPattern,
+    ) -> SplitEvery<ViewInput, ViewPattern> {
+        SplitEvery {
+            input,
+            pat,
+            n: self.n,
+            ind: self.ind,
+            back: self.back,
+            finished: self.finished,
+            allow_trailing_empty: self.allow_trailing_empty,
+            back_aligned: self.back_aligned,
+            keep_trailing_empty: self.keep_trailing_empty,
+            inclusive: self.inclusive,
+            lossless: self.lossless,
+            overlapping: self.overlapping,
+            skip_empty: self.skip_empty,
+            trim: self.trim,
+            require_pattern: self.require_pattern,
+            min_chunk_len: self.min_chunk_len,
+            chunk_capacity: self.chunk_capacity,
+            max_splits: self.max_splits,
+            split_limit: self.split_limit,
         }
-        if let Some((ind, _)) = unsafe { iter_haystack.get_unchecked(len..) }
-            .windows(pat.len())
-            .enumerate()
-            .find(|(_, val)| val == &pat)
-        {
-            len = unsafe { len.unchecked_add(ind).unchecked_add(pat.len()) };
-            continue;
+    }
+
+    /// Returns where the unsplit input starts, or `input_len` once exhausted.
+    fn offset(&self, input_len: usize) -> usize {
+        if self.finished {
+            input_len
+        } else {
+            self.ind
+        }
+    }
+
+    /// Moves the start of the unsplit input to `offset`, within `input_len`.
+    fn seek(&mut self, offset: usize, input_len: usize) {
+        assert!(
+            offset <= input_len - self.back,
+            "offset {offset} is past the unsplit input"
+        );
+        self.ind = offset;
+        self.finished = false;
+        self.back_aligned = false;
+    }
+
+    fn remaining_range(&self, input_len: usize) -> Range<usize> {
+        let range: Range<usize> = self.unsplit_range(input_len);
+        if self.finished {
+            return range.start..range.start;
         }
-        if ind == 0 {
-            return (input.len(), Some(iter_haystack.to_vec()));
+        range
+    }
+
+    /// Returns the range between the parts consumed from either end, even once finished.
+    ///
+    /// Every chunk is sliced from within this range, so it is checked here rather than
+    /// trusted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parts consumed from either end overlap, which iteration never leads to.
+    fn unsplit_range(&self, input_len: usize) -> Range<usize> {
+        match input_len.checked_sub(self.back) {
+            Some(end) if self.ind <= end => self.ind..end,
+            _ => panic!(
+                "position {}..{} - {} is outside the input",
+                self.ind, input_len, self.back
+            ),
+        }
+    }
+
+    /// Copies the iteration state of `other` onto this iterator.
+    fn copy_state<OtherInput, OtherPattern>(
+        &mut self,
+        other: &SplitEvery<OtherInput, OtherPattern>,
+    ) {
+        self.n = other.n;
+        self.ind = other.ind;
+        self.back = other.back;
+        self.finished = other.finished;
+        self.allow_trailing_empty = other.allow_trailing_empty;
+        self.back_aligned = other.back_aligned;
+        self.keep_trailing_empty = other.keep_trailing_empty;
+        self.inclusive = other.inclusive;
+        self.lossless = other.lossless;
+        self.overlapping = other.overlapping;
+        self.skip_empty = other.skip_empty;
+        self.trim = other.trim;
+        self.require_pattern = other.require_pattern;
+        self.min_chunk_len = other.min_chunk_len;
+        self.chunk_capacity = other.chunk_capacity;
+        self.max_splits = other.max_splits;
+        self.split_limit = other.split_limit;
+    }
+
+    /// Replaces the pattern, keeping the iteration state.
+    fn map_pattern<NewPattern>(
+        self,
+        f: impl FnOnce(Pattern) -> NewPattern,
+    ) -> SplitEvery<Input, NewPattern> {
+        SplitEvery {
+            input: self.input,
+            pat: f(self.pat),
+            n: self.n,
+            ind: self.ind,
+            back: self.back,
+            finished: self.finished,
+            allow_trailing_empty: self.allow_trailing_empty,
+            back_aligned: self.back_aligned,
+            keep_trailing_empty: self.keep_trailing_empty,
+            inclusive: self.inclusive,
+            lossless: self.lossless,
+            overlapping: self.overlapping,
+            skip_empty: self.skip_empty,
+            trim: self.trim,
+            require_pattern: self.require_pattern,
+            min_chunk_len: self.min_chunk_len,
+            chunk_capacity: self.chunk_capacity,
+            max_splits: self.max_splits,
+            split_limit: self.split_limit,
         }
-        break;
     }
-    (
-        unsafe { split_every.ind.unchecked_add(len) },
-        Some(unsafe { iter_haystack.get_unchecked(..len.unchecked_sub(pat.len())) }.to_vec()),
-    )
 }
 
-#[test]
-fn test() {
-    let mut splitter: SplitEvery<&str, &str> = "oh oh oh oh oh".split_every_n_times(" ", 2);
-    assert_eq!(splitter.next().unwrap(), "oh oh");
-    assert_eq!(splitter.next().unwrap(), "oh oh");
-    assert_eq!(splitter.next().unwrap(), "oh");
-    assert_eq!(splitter.next(), None);
+impl<Input> SplitEvery<Input, ()> {
+    /// Starts configuring a splitter over `input`, see [`SplitEveryOptions`].
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// let splitter: SplitEvery<&str, &str> = SplitEvery::builder("a,b,c,d")
+    ///     .pattern(",")
+    ///     .every(1)
+    ///     .inclusive(true)
+    ///     .max_splits(2)
+    ///     .build();
+    /// assert_eq!(splitter.collect::<Vec<String>>(), vec!["a,", "b,c,d"]);
+    /// ```
+    pub fn builder(input: Input) -> SplitEveryOptions<Input, ()> {
+        SplitEveryOptions(SplitEvery::new(input, (), 1))
+    }
+}
 
-    let mut splitter: SplitEvery<&str, &str> = "a a a a".split_every_n_times("b", 2);
-    assert_eq!(splitter.next().unwrap(), "a a a a");
-    assert_eq!(splitter.next(), None);
+/// Configures a [`SplitEvery`] one option at a time.
+///
+/// Created by [`SplitEvery::builder`]. Every option defaults to the behavior of
+/// `split_every_n_times`, and `n` defaults to `1`.
+#[derive(Clone)]
+pub struct SplitEveryOptions<Input, Pattern>(SplitEvery<Input, Pattern>);
 
-    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> = [
-        ["This", "is", "you"],
-        ["This", "is", "me"],
-        ["This", "is", "someone"],
-        ["This", "is", "them"],
-    ]
-    .iter()
-    .flatten()
-    .copied()
-    .split_every_n_times("is", 2);
-    assert_eq!(splitter.next().unwrap(), vec!["This", "is", "you", "This"]);
-    assert_eq!(
-        splitter.next().unwrap(),
-        vec!["me", "This", "is", "someone", "This"]
-    );
-    assert_eq!(splitter.next().unwrap(), vec!["them"]);
-    assert_eq!(splitter.next(), None);
+impl<Input, Pattern> SplitEveryOptions<Input, Pattern> {
+    /// Sets the pattern to split on.
+    pub fn pattern<NewPattern>(self, pat: NewPattern) -> SplitEveryOptions<Input, NewPattern> {
+        SplitEveryOptions(self.0.map_pattern(|_| pat))
+    }
 
-    let mut iter = [
-        ["This", "is", "you"],
-        ["This", "is", "me"],
-        ["This", "is", "someone"],
-        ["This", "is", "them"],
-    ]
-    .iter()
-    .flatten()
-    .copied();
-    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> =
-        SplitEvery::<Box<dyn FnMut() -> Option<&'static str>>, &str>::n_times_from_fn(
-            Box::new(move || iter.next()),
-            "is",
-            2,
-        );
+    /// Sets how many occurrences of the pattern end a chunk.
+    pub fn every(mut self, n: usize) -> Self {
+        self.0.n = n;
+        self
+    }
+
+    /// Keeps the `n`th pattern at the end of each chunk.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.0.inclusive = inclusive;
+        self
+    }
+
+    /// Keeps every pattern so the chunks join back into the input, see
+    /// [`SplitEvery::lossless`].
+    pub fn lossless(self, lossless: bool) -> Self {
+        SplitEveryOptions(self.0.lossless(lossless))
+    }
+
+    /// Counts overlapping occurrences of the pattern, see [`SplitEvery::overlapping`].
+    pub fn overlapping(self, overlapping: bool) -> Self {
+        SplitEveryOptions(self.0.overlapping(overlapping))
+    }
+
+    /// Limits the number of chunks, see [`SplitEvery::with_max_splits`].
+    pub fn max_splits(self, max_splits: usize) -> Self {
+        SplitEveryOptions(self.0.with_max_splits(max_splits))
+    }
+
+    /// Drops empty chunks, see [`SplitEvery::skip_empty`].
+    pub fn skip_empty(self, skip_empty: bool) -> Self {
+        SplitEveryOptions(self.0.skip_empty(skip_empty))
+    }
+
+    /// Yields a final empty chunk after a trailing pattern, see
+    /// [`SplitEvery::keep_trailing_empty`].
+    pub fn keep_trailing_empty(self, keep_trailing_empty: bool) -> Self {
+        SplitEveryOptions(self.0.keep_trailing_empty(keep_trailing_empty))
+    }
+
+    /// Trims whitespace off each string chunk, see [`SplitEvery::trim_chunks`].
+    pub fn trim_chunks(self, trim: bool) -> Self {
+        SplitEveryOptions(self.0.trim_chunks(trim))
+    }
+
+    /// Yields nothing for input lacking the pattern, see [`SplitEvery::require_pattern`].
+    pub fn require_pattern(self, require_pattern: bool) -> Self {
+        SplitEveryOptions(self.0.require_pattern(require_pattern))
+    }
+
+    /// Merges chunks shorter than `min_chunk_len`, see [`SplitEvery::min_chunk_len`].
+    pub fn min_chunk_len(self, min_chunk_len: usize) -> Self {
+        SplitEveryOptions(self.0.min_chunk_len(min_chunk_len))
+    }
+
+    /// Preallocates chunks collected from an iterator, see [`SplitEvery::chunk_capacity`].
+    pub fn chunk_capacity(self, chunk_capacity: usize) -> Self {
+        SplitEveryOptions(self.0.chunk_capacity(chunk_capacity))
+    }
+
+    /// Matches the pattern ignoring ASCII case.
+    pub fn ignore_ascii_case(self) -> SplitEveryOptions<Input, IgnoreAsciiCase<Pattern>> {
+        SplitEveryOptions(self.0.map_pattern(IgnoreAsciiCase))
+    }
+
+    /// Finishes configuring the splitter.
+    pub fn build(self) -> SplitEvery<Input, Pattern>
+    where
+        SplitEvery<Input, Pattern>: Iterator,
+    {
+        self.0
+    }
+}
+
+impl<Pattern> SplitEveryOptions<(), Pattern> {
+    /// Finishes configuring a reusable [`SplitEverySpec`] instead of a splitter.
+    pub fn spec(self) -> SplitEverySpec<Pattern> {
+        SplitEverySpec(self.0)
+    }
+}
+
+impl<Input, Pattern> Debug for SplitEveryOptions<Input, Pattern>
+where
+    SplitEvery<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SplitEveryOptions").field(&self.0).finish()
+    }
+}
+
+/// A reusable split configuration, applied to any number of inputs.
+///
+/// Options are set through [`SplitEverySpec::builder`], which shares its setters with
+/// [`SplitEvery::builder`].
+///
+/// ```rust
+/// use split_every::prelude::*;
+///
+/// let spec: SplitEverySpec<&str> = SplitEverySpec::builder()
+///     .pattern(",")
+///     .every(2)
+///     .inclusive(true)
+///     .spec();
+/// assert_eq!(spec.apply("a,b,c").collect::<Vec<String>>(), ["a,b,", "c"]);
+/// assert_eq!(spec.apply("d,e").collect::<Vec<String>>(), ["d,e"]);
+/// ```
+#[derive(Clone)]
+pub struct SplitEverySpec<Pattern>(SplitEvery<(), Pattern>);
+
+impl SplitEverySpec<()> {
+    /// Starts configuring a spec, finished by [`SplitEveryOptions::spec`].
+    pub fn builder() -> SplitEveryOptions<(), ()> {
+        SplitEvery::builder(())
+    }
+}
+
+impl<Pattern> SplitEverySpec<Pattern> {
+    /// Splits every `n` occurrences of `pat`, with every other option at its default.
+    pub fn new(pat: Pattern, n: usize) -> Self {
+        SplitEverySpec(SplitEvery::new((), pat, n))
+    }
+
+    /// Starts splitting `input` with a copy of this configuration.
+    pub fn apply<Input>(&self, input: Input) -> SplitEvery<Input, Pattern>
+    where
+        Pattern: Clone,
+        SplitEvery<Input, Pattern>: Iterator,
+    {
+        self.0.with_parts(input, self.0.pat.clone())
+    }
+}
+
+impl<Pattern: Debug> Debug for SplitEverySpec<Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut f: DebugStruct<'_, '_> = f.debug_struct("SplitEverySpec");
+        f.field("pat", &self.0.pat).field("n", &self.0.n);
+        split_every_fmt_options_helper(&self.0, &mut f).finish()
+    }
+}
+
+impl<Input: SplitEveryInput, Pattern> SplitEvery<Input, Pattern> {
+    /// Returns the part of the input that has not been split yet.
+    ///
+    /// Once the iterator is exhausted, this is empty. Borrowed input keeps its lifetime, so
+    /// the remainder of a `&'a str` is a `&'a str`.
+    pub fn remainder(&self) -> Input::Part<'_> {
+        self.input
+            .part(self.remaining_range(self.input.as_ref().len()))
+    }
+
+    /// Restarts iteration from the beginning of the input.
+    pub fn reset(&mut self) {
+        self.rewind();
+    }
+
+    /// Resumes splitting from `offset`, as returned by `byte_offset` for strings or `index`
+    /// for slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the unsplit input, or is not on a char boundary of a string.
+    pub fn seek_to(&mut self, offset: usize) {
+        let input: &Input::Haystack = self.input.as_ref();
+        assert!(
+            input.is_boundary(offset),
+            "offset {offset} is not on a char boundary"
+        );
+        let input_len: usize = input.len();
+        self.seek(offset, input_len);
+    }
+
+    /// Returns the chunk the next call to `next` would yield, without advancing.
+    ///
+    /// For slices, the search runs on a clone of the pattern, so stateful patterns are not
+    /// advanced.
+    pub fn peek_chunk(&self) -> Option<Input::Part<'_>>
+    where
+        Input::Haystack: SplitEverySearch<Pattern>,
+    {
+        let range: Range<usize> = Input::Haystack::peek(self)?;
+        Some(self.input.part(range))
+    }
+
+    /// Counts the occurrences of the pattern left in the remaining input, without advancing.
+    ///
+    /// Occurrences are counted the way `next` finds them, so each call to `next` lowers the
+    /// count by up to `n`. This scans the whole remaining input, taking O(remaining length).
+    pub fn occurrences_left(&self) -> usize
+    where
+        Input::Haystack: SplitEverySearch<Pattern>,
+    {
+        Input::Haystack::occurrences(self)
+    }
+
+    /// Counts the chunks `next` would still yield, without advancing.
+    ///
+    /// Unlike `occurrences_left`, this accounts for `n` and every option, so it always equals
+    /// `self.clone().count()`, which makes it suited to pre-sizing a `Vec` of the results.
+    /// This scans the whole remaining input, taking O(remaining length).
+    pub fn chunks_remaining(&self) -> usize
+    where
+        Input::Haystack: SplitEverySearch<Pattern>,
+    {
+        Input::Haystack::chunks_remaining(self)
+    }
+
+    /// Replaces the contents of `buf` with the next chunk, returning `false` once exhausted.
+    ///
+    /// Reusing one buffer avoids allocating a `String` or `Vec` per chunk.
+    pub fn next_into(
+        &mut self,
+        buf: &mut <Input::Haystack as SplitEverySearch<Pattern>>::Owned,
+    ) -> bool
+    where
+        Input::Haystack: SplitEverySearch<Pattern>,
+    {
+        Input::Haystack::next_into(self, buf)
+    }
+
+    /// Folds the chunks into an accumulator, handing each to `f` borrowed from the input.
+    ///
+    /// `fold` has to give its closure every chunk as an owned `Item`, so it copies each one.
+    /// This never does, but `f` only sees a borrow: keeping a chunk means copying it there.
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// let splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 2);
+    /// assert_eq!(splitter.fold_chunks(0, |total, chunk| total + chunk.len()), 7);
+    /// ```
+    pub fn fold_chunks<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Input::Haystack: SplitEverySearch<Pattern> + Index<Range<usize>, Output = Input::Haystack>,
+        F: FnMut(B, &Input::Haystack) -> B,
+    {
+        let mut acc: B = init;
+        while let Some(range) = Input::Haystack::next_range(&mut self) {
+            acc = f(acc, &self.input.as_ref()[range]);
+        }
+        acc
+    }
+}
+
+impl<Input: SplitEveryInput<Haystack = str>, Pattern> SplitEvery<Input, Pattern> {
+    /// Returns the byte offset where the unsplit input starts, which is the input's length
+    /// once exhausted.
+    ///
+    /// Chunks taken from the back do not move it.
+    pub fn byte_offset(&self) -> usize {
+        self.offset(self.input.as_ref().len())
+    }
+
+    /// Joins the remaining chunks with `sep` into a single string.
+    ///
+    /// Chunks are appended straight from the input, so no `String` is allocated per chunk.
+    pub fn join_with(mut self, sep: &str) -> String
+    where
+        Pattern: StrPattern,
+    {
+        split_every_join_str_helper(&mut self, sep)
+    }
+}
+
+impl<T, Input: SplitEveryInput<Haystack = [T]>, Pattern> SplitEvery<Input, Pattern> {
+    /// Returns the index where the unsplit input starts, which is the input's length once
+    /// exhausted.
+    ///
+    /// Chunks taken from the back do not move it.
+    pub fn index(&self) -> usize {
+        self.offset(self.input.as_ref().len())
+    }
+}
+
+impl<T, Pattern> SplitEvery<alloc::vec::Drain<'_, T>, Pattern> {
+    /// Returns the elements that have not been drained into a chunk yet.
+    pub fn remainder(&self) -> &[T] {
+        self.input.as_slice()
+    }
+}
+
+/// An input that [`SplitEvery`] splits in place, viewed as a `str` or a `[T]`.
+///
+/// Implemented for every string and slice input, so that the methods inspecting and
+/// repositioning a splitter, such as [`SplitEvery::remainder`], are shared by all of them.
+pub trait SplitEveryInput: AsRef<Self::Haystack> {
+    /// `str` for string input and `[T]` for slice input.
+    type Haystack: SplitEveryHaystack + ?Sized;
+
+    /// A part of the input, borrowed for as long as the input itself is.
+    type Part<'s>
+    where
+        Self: 's;
+
+    /// Returns `range` of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or is not on char boundaries of a string.
+    fn part(&self, range: Range<usize>) -> Self::Part<'_>;
+}
+
+impl<'a> SplitEveryInput for &'a str {
+    type Haystack = str;
+    type Part<'s>
+        = &'a str
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &'a str {
+        let input: &'a str = self;
+        &input[range]
+    }
+}
+
+#[cfg(feature = "std")]
+impl SplitEveryInput for String {
+    type Haystack = str;
+    type Part<'s> = &'s str;
+
+    fn part(&self, range: Range<usize>) -> &str {
+        &self[range]
+    }
+}
+
+#[cfg(feature = "std")]
+impl SplitEveryInput for Cow<'_, str> {
+    type Haystack = str;
+    type Part<'s>
+        = &'s str
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &str {
+        &self[range]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> SplitEveryInput for &'a String {
+    type Haystack = str;
+    type Part<'s>
+        = &'a str
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &'a str {
+        let input: &'a String = self;
+        &input[range]
+    }
+}
+
+#[cfg(feature = "std")]
+impl SplitEveryInput for &mut String {
+    type Haystack = str;
+    type Part<'s>
+        = &'s str
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &str {
+        &self[range]
+    }
+}
+
+#[cfg(feature = "std")]
+impl SplitEveryInput for alloc::string::Drain<'_> {
+    type Haystack = str;
+    type Part<'s>
+        = &'s str
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &str {
+        &self.as_str()[range]
+    }
+}
+
+impl<T> SplitEveryInput for Vec<T> {
+    type Haystack = [T];
+    type Part<'s>
+        = &'s [T]
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &[T] {
+        &self[range]
+    }
+}
+
+impl<T, const N: usize> SplitEveryInput for [T; N] {
+    type Haystack = [T];
+    type Part<'s>
+        = &'s [T]
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &[T] {
+        &self[range]
+    }
+}
+
+impl<'a, T> SplitEveryInput for &'a [T] {
+    type Haystack = [T];
+    type Part<'s>
+        = &'a [T]
+    where
+        Self: 's;
+
+    fn part(&self, range: Range<usize>) -> &'a [T] {
+        let input: &'a [T] = self;
+        &input[range]
+    }
+}
+
+/// Parts share the input's buffer instead of copying it.
+#[cfg(feature = "bytes")]
+impl SplitEveryInput for bytes::Bytes {
+    type Haystack = [u8];
+    type Part<'s> = bytes::Bytes;
+
+    fn part(&self, range: Range<usize>) -> bytes::Bytes {
+        self.slice(range)
+    }
+}
+
+/// The view of a [`SplitEveryInput`], `str` or `[T]`.
+pub trait SplitEveryHaystack {
+    /// Returns the length in bytes of a `str`, or in elements of a `[T]`.
+    fn len(&self) -> usize;
+
+    /// Returns whether the view is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether splitting may resume at `offset`, which for a `str` means it is on a
+    /// char boundary.
+    fn is_boundary(&self, offset: usize) -> bool;
+}
+
+impl SplitEveryHaystack for str {
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn is_boundary(&self, offset: usize) -> bool {
+        self.is_char_boundary(offset)
+    }
+}
+
+impl<T> SplitEveryHaystack for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn is_boundary(&self, _offset: usize) -> bool {
+        true
+    }
+}
+
+/// A [`SplitEveryHaystack`] that can be searched with `Pattern` without going through
+/// `Iterator`, a `str` for a [`StrPattern`] or a `[T]` for an [`ArrPattern`].
+///
+/// Slices need a `Clone` pattern, since [`ArrPattern::find_in`] may change it and looking
+/// ahead must not.
+pub trait SplitEverySearch<Pattern>: SplitEveryHaystack {
+    /// A chunk owned apart from the input, `String` for `str` and `Vec<T>` for `[T]`.
+    type Owned;
+
+    /// Returns the range of the chunk `split_every` would yield next, without advancing.
+    fn peek<Input: AsRef<Self>>(split_every: &SplitEvery<Input, Pattern>) -> Option<Range<usize>>;
+
+    /// Counts the occurrences of the pattern within the remaining input.
+    fn occurrences<Input: AsRef<Self>>(split_every: &SplitEvery<Input, Pattern>) -> usize;
+
+    /// Counts the chunks `split_every` would still yield.
+    fn chunks_remaining<Input: AsRef<Self>>(split_every: &SplitEvery<Input, Pattern>) -> usize;
+
+    /// Replaces the contents of `buf` with the next chunk, returning `false` once exhausted.
+    fn next_into<Input: AsRef<Self>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+        buf: &mut Self::Owned,
+    ) -> bool;
+
+    /// Returns the range of the next chunk, advancing past it.
+    fn next_range<Input: AsRef<Self>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+    ) -> Option<Range<usize>>;
+}
+
+impl<Pattern: StrPattern> SplitEverySearch<Pattern> for str {
+    type Owned = String;
+
+    fn peek<Input: AsRef<str>>(split_every: &SplitEvery<Input, Pattern>) -> Option<Range<usize>> {
+        split_every_peek_str_helper(split_every)
+    }
+
+    fn occurrences<Input: AsRef<str>>(split_every: &SplitEvery<Input, Pattern>) -> usize {
+        split_every_occurrences_str_helper(split_every)
+    }
+
+    fn chunks_remaining<Input: AsRef<str>>(split_every: &SplitEvery<Input, Pattern>) -> usize {
+        split_every_chunks_remaining_str_helper(split_every)
+    }
+
+    fn next_into<Input: AsRef<str>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+        buf: &mut String,
+    ) -> bool {
+        split_every_next_into_str_helper(split_every, buf)
+    }
+
+    fn next_range<Input: AsRef<str>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+    ) -> Option<Range<usize>> {
+        split_every_next_str_helper(split_every)
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T> + Clone> SplitEverySearch<Pattern> for [T] {
+    type Owned = Vec<T>;
+
+    fn peek<Input: AsRef<[T]>>(split_every: &SplitEvery<Input, Pattern>) -> Option<Range<usize>> {
+        split_every_peek_arr_helper(split_every)
+    }
+
+    fn occurrences<Input: AsRef<[T]>>(split_every: &SplitEvery<Input, Pattern>) -> usize {
+        split_every_occurrences_arr_helper(split_every)
+    }
+
+    fn chunks_remaining<Input: AsRef<[T]>>(split_every: &SplitEvery<Input, Pattern>) -> usize {
+        split_every_chunks_remaining_arr_helper(split_every)
+    }
+
+    fn next_into<Input: AsRef<[T]>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+        buf: &mut Vec<T>,
+    ) -> bool {
+        split_every_next_into_arr_helper(split_every, buf)
+    }
+
+    fn next_range<Input: AsRef<[T]>>(
+        split_every: &mut SplitEvery<Input, Pattern>,
+    ) -> Option<Range<usize>> {
+        split_every_next_arr_helper(split_every)
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> SplitEvery<Input, Pattern> {
+    /// Splits the values returned by `input` every `n` occurrences of `pat`, until it
+    /// returns `None`.
+    ///
+    /// When the source ends mid-group, the values read so far form the final chunk, with
+    /// any fewer than `n` patterns kept in it. When it ends right after an `n`th pattern,
+    /// no empty chunk follows, as with [`str::split_terminator`]. The source is never
+    /// called again once it has returned `None`.
+    pub fn n_times_from_fn(input: Input, pat: Pattern, n: usize) -> SplitEvery<Input, Pattern> {
+        SplitEvery::new(input, pat, n)
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> Iterator
+    for SplitEvery<Input, Pattern>
+{
+    type Item = Vec<Pattern>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(self, |input| input(), |pat, val| val == pat)
+    }
+}
+
+impl<T, Input: FnMut() -> Option<T>, F: FnMut(&T) -> bool> Iterator for SplitEvery<Input, By<F>> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(
+            self,
+            |input| input(),
+            |pat, val| (pat.0)(val),
+        )
+    }
+}
+
+/// Drains the source vector element by element, like a closure input.
+impl<T: PartialEq> Iterator for SplitEvery<alloc::vec::Drain<'_, T>, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(self, Iterator::next, |pat, val| val == pat)
+    }
+}
+
+/// Collects the elements of a chunk in the closure path.
+trait CollectChunk<T> {
+    type Chunk;
+
+    fn collect_chunk(iter: impl Iterator<Item = T>, capacity: usize) -> Self::Chunk;
+}
+
+impl<T> CollectChunk<T> for Vec<T> {
+    type Chunk = Vec<T>;
+
+    fn collect_chunk(iter: impl Iterator<Item = T>, capacity: usize) -> Self::Chunk {
+        let mut out: Vec<T> = Vec::with_capacity(capacity);
+        out.extend(iter);
+        out
+    }
+}
+
+/// Collects into any `Chunk`, ignoring the capacity.
+impl<T, Chunk: FromIterator<T>> CollectChunk<T> for PhantomData<Chunk> {
+    type Chunk = Chunk;
+
+    fn collect_chunk(iter: impl Iterator<Item = T>, _: usize) -> Self::Chunk {
+        iter.collect()
+    }
+}
+
+fn split_every_next_fn_helper<T, Input, Pattern, Collect: CollectChunk<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    mut next: impl FnMut(&mut Input) -> Option<T>,
+    mut is_match: impl FnMut(&mut Pattern, &T) -> bool,
+) -> Option<Collect::Chunk> {
+    loop {
+        if split_every.finished || split_every.max_splits == Some(0) {
+            split_every.finished = true;
+            return None;
+        }
+        let max_splits: Option<usize> = split_every.max_splits;
+        let is_whole: bool = !split_every.take_chunk();
+        let n: usize = split_every.n;
+        let inclusive: bool = split_every.keeps_pattern();
+        let chunk_capacity: usize = split_every.chunk_capacity;
+        let SplitEvery {
+            input,
+            pat,
+            finished,
+            ..
+        } = &mut *split_every;
+        let mut is_empty: bool = true;
+        let mut len: usize = 0;
+        let mut found: usize = 0;
+        let mut done: bool = false;
+        let chunks = core::iter::from_fn(|| {
+            if done {
+                return None;
+            }
+            let Some(val) = next(input) else {
+                *finished = true;
+                done = true;
+                return None;
+            };
+            // Even an empty chunk consumed its terminating pattern from the source.
+            is_empty = false;
+            if !is_whole && is_match(pat, &val) {
+                found = unsafe { found.unchecked_add(1) };
+                if found == n {
+                    done = true;
+                    if !inclusive {
+                        return None;
+                    }
+                }
+            }
+            len = unsafe { len.unchecked_add(1) };
+            Some(val)
+        });
+        let out: Collect::Chunk = Collect::collect_chunk(chunks, chunk_capacity);
+        if is_whole {
+            *finished = true;
+        }
+        if is_empty {
+            *finished = true;
+            // An empty source, or one that ended right after an `n`th pattern.
+            if !split_every.allows_trailing_empty() {
+                return None;
+            }
+        }
+        if split_every.skip_empty && len == 0 {
+            split_every.max_splits = max_splits;
+            continue;
+        }
+        return Some(out);
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> FusedIterator
+    for SplitEvery<Input, Pattern>
+{
+}
+
+impl<T, Input: FnMut() -> Option<T>, F: FnMut(&T) -> bool> FusedIterator
+    for SplitEvery<Input, By<F>>
+{
+}
+
+impl<T: PartialEq> FusedIterator for SplitEvery<alloc::vec::Drain<'_, T>, T> {}
+
+/// Matches the wrapped element only at positions that are a multiple of a stride.
+///
+/// Built by [`split_every_n_times_strided`](SplitEveryIterImpl::split_every_n_times_strided).
+#[derive(Clone, Debug)]
+pub struct Strided<T> {
+    pat: T,
+    stride: usize,
+    /// Position of the next item read from the source.
+    pos: usize,
+}
+
+impl<T: PartialEq, Input: FnMut() -> Option<T>> Iterator for SplitEvery<Input, Strided<T>> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(
+            self,
+            |input| input(),
+            |pat, val| {
+                let pos: usize = pat.pos;
+                pat.pos = pos.wrapping_add(1);
+                pos.is_multiple_of(pat.stride) && *val == pat.pat
+            },
+        )
+    }
+}
+
+impl<T: PartialEq, Input: FnMut() -> Option<T>> FusedIterator for SplitEvery<Input, Strided<T>> {}
+
+/// A closure source that keeps a clone of its original state, so splitting it can restart.
+///
+/// Built by [`SplitEvery::n_times_from_cloneable_fn`].
+#[derive(Clone)]
+pub struct CloneableFn<F> {
+    source: F,
+    current: F,
+}
+
+impl<F> Debug for CloneableFn<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CloneableFn(<fn>)")
+    }
+}
+
+impl<F: FnMut() -> Option<Pattern> + Clone, Pattern: PartialEq>
+    SplitEvery<CloneableFn<F>, Pattern>
+{
+    /// Like [`n_times_from_fn`](SplitEvery::n_times_from_fn), but `input` must be `Clone` so
+    /// that `reset` can restart from a clone of it taken before the first call.
+    ///
+    /// Cloning must capture everything the closure reads from, so this suits closures
+    /// wrapping cheaply cloneable sources such as counters or slice iterators, not ones
+    /// reading from shared state.
+    pub fn n_times_from_cloneable_fn(
+        input: F,
+        pat: Pattern,
+        n: usize,
+    ) -> SplitEvery<CloneableFn<F>, Pattern> {
+        SplitEvery::new(
+            CloneableFn {
+                current: input.clone(),
+                source: input,
+            },
+            pat,
+            n,
+        )
+    }
+}
+
+impl<F: Clone, Pattern> SplitEvery<CloneableFn<F>, Pattern> {
+    /// Restarts iteration from a fresh clone of the original closure.
+    pub fn reset(&mut self) {
+        self.input.current = self.input.source.clone();
+        self.rewind();
+    }
+}
+
+impl<F: FnMut() -> Option<Pattern>, Pattern: PartialEq> Iterator
+    for SplitEvery<CloneableFn<F>, Pattern>
+{
+    type Item = Vec<Pattern>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(
+            self,
+            |input| (input.current)(),
+            |pat, val| val == pat,
+        )
+    }
+}
+
+impl<F: FnMut() -> Option<Pattern>, Pattern: PartialEq> FusedIterator
+    for SplitEvery<CloneableFn<F>, Pattern>
+{
+}
+
+impl<T, Pattern> SplitEvery<VecDeque<T>, Pattern> {
+    /// Restarts iteration from the beginning of the input.
+    pub fn reset(&mut self) {
+        self.rewind();
+    }
+
+    /// Returns the index where the unsplit input starts, which is the input's length once
+    /// exhausted.
+    ///
+    /// Chunks taken from the back do not move it.
+    pub fn index(&self) -> usize {
+        self.offset(self.input.len())
+    }
+
+    /// Resumes splitting from index `offset`, as returned by `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the unsplit input.
+    pub fn seek_to(&mut self, offset: usize) {
+        self.seek(offset, self.input.len());
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern> Debug for SplitEvery<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEvery")
+            .field("input", &format_args!("<fn>"))
+            .field("n", &self.n)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEvery<Vec<T>, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+impl<T: Debug, const N: usize, Pattern: Debug> Debug for SplitEvery<[T; N], Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEvery<alloc::vec::Drain<'_, T>, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: Debug> Debug for SplitEvery<&OsStr, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: Debug> Debug for SplitEvery<OsString, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<Pattern: Debug> Debug for SplitEvery<bytes::Bytes, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEvery<VecDeque<T>, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEvery<&[T], Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(self, "SplitEvery", f)
+    }
+}
+
+fn split_every_fmt_helper<Input: Debug, Pattern: Debug>(
+    split_every: &SplitEvery<Input, Pattern>,
+    name: &str,
+    f: &mut Formatter<'_>,
+) -> core::fmt::Result {
+    let mut f: DebugStruct<'_, '_> = f.debug_struct(name);
+    f.field("input", &split_every.input)
+        .field("pat", &split_every.pat)
+        .field("n", &split_every.n)
+        .field("ind", &split_every.ind);
+    split_every_fmt_options_helper(split_every, &mut f).finish_non_exhaustive()
+}
+
+/// Adds the options that differ from their defaults, so differently configured splitters
+/// print differently.
+fn split_every_fmt_options_helper<'a, 'b, 'c, Input, Pattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+    f: &'a mut DebugStruct<'b, 'c>,
+) -> &'a mut DebugStruct<'b, 'c> {
+    let flags: [(&str, bool); 7] = [
+        ("inclusive", split_every.inclusive),
+        ("lossless", split_every.lossless),
+        ("overlapping", split_every.overlapping),
+        ("skip_empty", split_every.skip_empty),
+        ("trim", split_every.trim),
+        ("require_pattern", split_every.require_pattern),
+        ("keep_trailing_empty", split_every.keep_trailing_empty),
+    ];
+    for (name, flag) in flags {
+        if flag {
+            f.field(name, &flag);
+        }
+    }
+    if let Some(max_splits) = split_every.split_limit {
+        f.field("max_splits", &max_splits);
+    }
+    if split_every.min_chunk_len != 0 {
+        f.field("min_chunk_len", &split_every.min_chunk_len);
+    }
+    if split_every.chunk_capacity != 0 {
+        f.field("chunk_capacity", &split_every.chunk_capacity);
+    }
+    f
+}
+
+/// Implements `Debug` and the iterator traits for string inputs, which only differ in how
+/// they are borrowed as a `str`.
+macro_rules! split_every_str_impls {
+    ($($(#[$attr:meta])* $input:ty),* $(,)?) => {$(
+        $(#[$attr])*
+        impl<Pattern: Debug> Debug for SplitEvery<$input, Pattern> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                split_every_fmt_helper(self, "SplitEvery", f)
+            }
+        }
+
+        $(#[$attr])*
+        impl<Pattern: StrPattern> Iterator for SplitEvery<$input, Pattern> {
+            type Item = String;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let range: Range<usize> = split_every_next_str_helper(self)?;
+                let input: &str = self.input.as_ref();
+                Some(unsafe { input.get_unchecked(range) }.to_string())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let input: &str = self.input.as_ref();
+                split_every_size_hint_helper(self, input.len())
+            }
+
+            fn count(mut self) -> usize {
+                core::iter::from_fn(|| split_every_next_str_helper(&mut self)).count()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                for _ in 0..n {
+                    split_every_next_str_helper(self)?;
+                }
+                self.next()
+            }
+
+            fn last(mut self) -> Option<Self::Item> {
+                let range: Range<usize> =
+                    core::iter::from_fn(|| split_every_next_str_helper(&mut self)).last()?;
+                let input: &str = self.input.as_ref();
+                Some(unsafe { input.get_unchecked(range) }.to_string())
+            }
+        }
+
+        $(#[$attr])*
+        impl<Pattern: StrPattern> DoubleEndedIterator for SplitEvery<$input, Pattern> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let range: Range<usize> = split_every_next_back_str_helper(self)?;
+                let input: &str = self.input.as_ref();
+                Some(unsafe { input.get_unchecked(range) }.to_string())
+            }
+        }
+
+        $(#[$attr])*
+        impl<Pattern: StrPattern> FusedIterator for SplitEvery<$input, Pattern> {}
+    )*};
+}
+
+split_every_str_impls!(
+    &str,
+    #[cfg(feature = "std")]
+    String,
+    #[cfg(feature = "std")]
+    Cow<'_, str>,
+    #[cfg(feature = "std")]
+    &String,
+    #[cfg(feature = "std")]
+    &mut String,
+    #[cfg(feature = "std")]
+    alloc::string::Drain<'_>,
+);
+
+/// Like [`SplitEvery`], but collects each chunk into `Chunk` instead of a [`Vec`].
+///
+/// Created by [`SplitEvery::collect_chunks_into`].
+pub struct SplitEveryInto<Input, Pattern, Chunk>(
+    SplitEvery<Input, Pattern>,
+    PhantomData<fn() -> Chunk>,
+);
+
+impl<Input: Clone, Pattern: Clone, Chunk> Clone for SplitEveryInto<Input, Pattern, Chunk> {
+    fn clone(&self) -> Self {
+        SplitEveryInto(self.0.clone(), PhantomData)
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq, Chunk: FromIterator<Pattern>> Iterator
+    for SplitEveryInto<Input, Pattern, Chunk>
+{
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, PhantomData<Chunk>>(
+            &mut self.0,
+            |input| input(),
+            |pat, val| val == pat,
+        )
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>, Chunk: FromIterator<T>> Iterator
+    for SplitEveryInto<Vec<T>, Pattern, Chunk>
+{
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(
+            unsafe { self.0.input.get_unchecked(range) }
+                .iter()
+                .cloned()
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>, Chunk: FromIterator<T>> Iterator
+    for SplitEveryInto<&[T], Pattern, Chunk>
+{
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(
+            unsafe { self.0.input.get_unchecked(range) }
+                .iter()
+                .cloned()
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq, Chunk: FromIterator<Pattern>>
+    FusedIterator for SplitEveryInto<Input, Pattern, Chunk>
+{
+}
+impl<T: Clone, Pattern: ArrPattern<T>, Chunk: FromIterator<T>> FusedIterator
+    for SplitEveryInto<Vec<T>, Pattern, Chunk>
+{
+}
+impl<T: Clone, Pattern: ArrPattern<T>, Chunk: FromIterator<T>> FusedIterator
+    for SplitEveryInto<&[T], Pattern, Chunk>
+{
+}
+
+impl<Input, Pattern, Chunk> Debug for SplitEveryInto<Input, Pattern, Chunk>
+where
+    SplitEvery<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SplitEveryInto").field(&self.0).finish()
+    }
+}
+
+/// Like [`SplitEvery`] over a closure, but drives a concrete iterator without boxing it.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "SplitEvery<Input, Pattern>: serde::Deserialize<'de>"))
+)]
+pub struct SplitEveryIter<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Input: Iterator<Item = Pattern>, Pattern: PartialEq> Iterator
+    for SplitEveryIter<Input, Pattern>
+{
+    type Item = Vec<Pattern>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_fn_helper::<_, _, _, Vec<_>>(&mut self.0, Iterator::next, |pat, val| {
+            val == pat
+        })
+    }
+}
+
+impl<Input: Iterator<Item = Pattern>, Pattern: PartialEq> FusedIterator
+    for SplitEveryIter<Input, Pattern>
+{
+}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryIter<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryIter", f)
+    }
+}
+
+impl<Input, Pattern> SplitEveryIter<Input, Pattern> {
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(self, max_splits: usize) -> Self {
+        SplitEveryIter(self.0.with_max_splits(max_splits))
+    }
+
+    /// Consumes the iterator, returning the source iterator, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        self.0.into_inner()
+    }
+}
+
+/// Like [`SplitEveryIter`], but over an iterator of `Result`s, stopping at the first `Err`.
+///
+/// Created by [`SplitEveryTryImpl::try_split_every_n_times`].
+#[derive(Clone)]
+pub struct SplitEveryTry<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<E, Input: Iterator<Item = Result<Pattern, E>>, Pattern: PartialEq> Iterator
+    for SplitEveryTry<Input, Pattern>
+{
+    type Item = Result<Vec<Pattern>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut err: Option<E> = None;
+        let chunk: Option<Vec<Pattern>> = split_every_next_fn_helper::<_, _, _, Vec<_>>(
+            &mut self.0,
+            |input| match input.next()? {
+                Ok(val) => Some(val),
+                Err(found) => {
+                    err = Some(found);
+                    None
+                }
+            },
+            |pat, val| val == pat,
+        );
+        // Running out of input marked the iterator finished, so nothing follows the error.
+        match err {
+            Some(err) => Some(Err(err)),
+            None => chunk.map(Ok),
+        }
+    }
+}
+
+impl<E, Input: Iterator<Item = Result<Pattern, E>>, Pattern: PartialEq> FusedIterator
+    for SplitEveryTry<Input, Pattern>
+{
+}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryTry<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryTry", f)
+    }
+}
+
+impl<Input, Pattern> SplitEveryTry<Input, Pattern> {
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(self, max_splits: usize) -> Self {
+        SplitEveryTry(self.0.with_max_splits(max_splits))
+    }
+
+    /// Consumes the iterator, returning the source iterator, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        self.0.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> SplitEvery<R, Vec<u8>> {
+    /// Streams chunks out of `reader`, splitting for every `n` occurrences of the byte pattern.
+    ///
+    /// The input is read in pieces, so it never has to be held in memory all at once.
+    pub fn n_times_from_read(reader: R, pat: Vec<u8>, n: usize) -> SplitEveryRead<R> {
+        SplitEveryRead {
+            reader,
+            pat,
+            n,
+            buf: Vec::new(),
+            scan: 0,
+            matches: 0,
+            buffer_size: 8 * 1024,
+            finished: false,
+        }
+    }
+}
+
+/// An iterator splitting the bytes of a [`Read`] for every `n` occurrences of a pattern.
+///
+/// Read errors are yielded as they occur; the iterator may be resumed after one.
+#[cfg(feature = "std")]
+pub struct SplitEveryRead<R> {
+    reader: R,
+    pat: Vec<u8>,
+    n: usize,
+    /// Bytes read but not yielded yet.
+    buf: Vec<u8>,
+    /// Index within `buf` from which to resume searching.
+    scan: usize,
+    /// Number of patterns found within `buf` so far.
+    matches: usize,
+    buffer_size: usize,
+    finished: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R> SplitEveryRead<R> {
+    /// Sets how many bytes are requested from the reader at a time.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    /// Consumes the iterator, returning the reader, pattern, and `n`.
+    ///
+    /// Bytes already read but not yielded yet are lost.
+    pub fn into_inner(self) -> (R, Vec<u8>, usize) {
+        (self.reader, self.pat, self.n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for SplitEveryRead<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        loop {
+            if self.n != 0 {
+                while let Some(split) = split_every_find_arr_helper(
+                    unsafe { self.buf.get_unchecked(self.scan..) },
+                    &self.pat,
+                ) {
+                    let split: Range<usize> = self.scan + split.start..self.scan + split.end;
+                    self.matches += 1;
+                    if self.matches == self.n {
+                        let mut out: Vec<u8> = self.buf.drain(..split.end).collect();
+                        out.truncate(split.start);
+                        self.scan = 0;
+                        self.matches = 0;
+                        return Some(Ok(out));
+                    }
+                    self.scan = split.end;
+                }
+                // A pattern may straddle the end of the buffer and the next read.
+                self.scan = self.scan.max(
+                    self.buf
+                        .len()
+                        .saturating_sub(self.pat.len().saturating_sub(1)),
+                );
+            }
+            let len: usize = self.buf.len();
+            self.buf.resize(len + self.buffer_size, 0);
+            match self
+                .reader
+                .read(unsafe { self.buf.get_unchecked_mut(len..) })
+            {
+                Ok(0) => {
+                    self.buf.truncate(len);
+                    self.finished = true;
+                    if self.buf.is_empty() {
+                        return None;
+                    }
+                    return Some(Ok(core::mem::take(&mut self.buf)));
+                }
+                Ok(read) => self.buf.truncate(len + read),
+                Err(err) => {
+                    self.buf.truncate(len);
+                    if err.kind() != io::ErrorKind::Interrupted {
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Debug> Debug for SplitEveryRead<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEveryRead")
+            .field("reader", &self.reader)
+            .field("pat", &self.pat)
+            .field("n", &self.n)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "futures")]
+pub trait SplitEveryStreamImpl<T>: Stream<Item = T> + Sized {
+    /// Splits the items of this stream for every `n` occurrences of `pat`.
+    fn split_every_n_times(self, pat: T, n: usize) -> SplitEveryStream<Self, T>
+    where
+        T: PartialEq,
+    {
+        SplitEveryStream {
+            stream: self,
+            pat,
+            n,
+            buf: Vec::new(),
+            matches: 0,
+            finished: false,
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T, S: Stream<Item = T> + Sized> SplitEveryStreamImpl<T> for S {}
+
+/// A [`Stream`] splitting the items of another stream for every `n` occurrences of a pattern.
+///
+/// Items are buffered across [`Poll::Pending`], and the last chunk is yielded once the
+/// underlying stream completes.
+#[cfg(feature = "futures")]
+pub struct SplitEveryStream<S, T> {
+    stream: S,
+    pat: T,
+    n: usize,
+    /// Items of the current chunk received so far.
+    buf: Vec<T>,
+    /// Number of patterns found within `buf` so far.
+    matches: usize,
+    finished: bool,
+}
+
+// The pattern and buffer are never pinned, so only the stream matters.
+#[cfg(feature = "futures")]
+impl<S: Unpin, T> Unpin for SplitEveryStream<S, T> {}
+
+#[cfg(feature = "futures")]
+impl<S, T> SplitEveryStream<S, T> {
+    /// Consumes the stream, returning the underlying stream, pattern, and `n`.
+    ///
+    /// Items already received but not yielded yet are lost.
+    pub fn into_inner(self) -> (S, T, usize) {
+        (self.stream, self.pat, self.n)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S: Stream<Item = T> + Unpin, T: PartialEq> Stream for SplitEveryStream<S, T> {
+    type Item = Vec<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this: &mut Self = self.get_mut();
+        if this.finished {
+            return Poll::Ready(None);
+        }
+        loop {
+            let Some(val) = core::task::ready!(Pin::new(&mut this.stream).poll_next(cx)) else {
+                this.finished = true;
+                if this.buf.is_empty() {
+                    return Poll::Ready(None);
+                }
+                return Poll::Ready(Some(core::mem::take(&mut this.buf)));
+            };
+            if this.n != 0 && val == this.pat {
+                this.matches += 1;
+                if this.matches == this.n {
+                    this.matches = 0;
+                    return Poll::Ready(Some(core::mem::take(&mut this.buf)));
+                }
+            }
+            this.buf.push(val);
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S: Stream<Item = T> + Unpin, T: PartialEq> FusedStream for SplitEveryStream<S, T> {
+    fn is_terminated(&self) -> bool {
+        self.finished
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S: Debug, T: Debug> Debug for SplitEveryStream<S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEveryStream")
+            .field("stream", &self.stream)
+            .field("pat", &self.pat)
+            .field("n", &self.n)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub trait SplitEveryParImpl<'a, T> {
+    /// Like `split_every_n_times`, but yields the chunks as a parallel iterator.
+    ///
+    /// Occurrences of `pat` are searched for in parallel, then grouped every `n` from the
+    /// start of the input, so the chunks are exactly those of the sequential iterator.
+    fn par_split_every_n_times(self, pat: &[T], n: usize) -> ParSplitEvery<'a, T>;
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: PartialEq + Sync> SplitEveryParImpl<'a, T> for &'a [T] {
+    fn par_split_every_n_times(self, pat: &[T], n: usize) -> ParSplitEvery<'a, T> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut start: usize = 0;
+        if n != 0 && !pat.is_empty() && pat.len() <= self.len() {
+            // Overlapping candidates are found in parallel; picking the leftmost
+            // non-overlapping ones has to happen in order.
+            let candidates: Vec<usize> = self
+                .par_windows(pat.len())
+                .enumerate()
+                .filter(|(_, window)| *window == pat)
+                .map(|(ind, _)| ind)
+                .collect();
+            let mut from: usize = 0;
+            let mut count: usize = 0;
+            for ind in candidates {
+                if ind < from {
+                    continue;
+                }
+                from = unsafe { ind.unchecked_add(pat.len()) };
+                count = unsafe { count.unchecked_add(1) };
+                if count == n {
+                    count = 0;
+                    ranges.push(start..ind);
+                    start = from;
+                }
+            }
+        }
+        if start != self.len() {
+            ranges.push(start..self.len());
+        }
+        ParSplitEvery {
+            input: self,
+            ranges,
+        }
+    }
+}
+
+/// A parallel iterator over the chunks of a slice, created by
+/// [`SplitEveryParImpl::par_split_every_n_times`].
+#[cfg(feature = "rayon")]
+#[derive(Clone, Debug)]
+pub struct ParSplitEvery<'a, T> {
+    input: &'a [T],
+    ranges: Vec<Range<usize>>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> ParSplitEvery<'a, T> {
+    fn into_chunks(self) -> impl IndexedParallelIterator<Item = &'a [T]> {
+        let input: &'a [T] = self.input;
+        self.ranges
+            .into_par_iter()
+            .map(move |range| unsafe { input.get_unchecked(range) })
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> ParallelIterator for ParSplitEvery<'a, T> {
+    type Item = &'a [T];
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.into_chunks().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.ranges.len())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Sync> IndexedParallelIterator for ParSplitEvery<'_, T> {
+    fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.into_chunks().drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.into_chunks().with_producer(callback)
+    }
+}
+
+/// Like [`SplitEvery`], but yields chunks borrowed from the input instead of allocating them.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "SplitEvery<Input, Pattern>: serde::Deserialize<'de>"))
+)]
+pub struct SplitEveryRef<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<'a, Pattern: StrPattern> Iterator for SplitEveryRef<&'a str, Pattern> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input: &'a str = self.0.input;
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        Some(unsafe { input.get_unchecked(range) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<'a, Pattern: StrPattern> DoubleEndedIterator for SplitEveryRef<&'a str, Pattern> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let input: &'a str = self.0.input;
+        let range: Range<usize> = split_every_next_back_str_helper(&mut self.0)?;
+        Some(unsafe { input.get_unchecked(range) })
+    }
+}
+
+impl<'a, T, Pattern: ArrPattern<T>> Iterator for SplitEveryRef<&'a [T], Pattern> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input: &'a [T] = self.0.input;
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(unsafe { input.get_unchecked(range) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<'a, T, Pattern: ArrPattern<T>> Iterator for SplitEveryRef<&'a mut [T], Pattern> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        // Hand the chunk out and keep only the input after it, so chunks never alias.
+        let input: &'a mut [T] = core::mem::take(&mut self.0.input);
+        let (head, tail) = input.split_at_mut(self.0.ind.max(range.end).min(input.len()));
+        self.0.input = tail;
+        self.0.ind = 0;
+        Some(&mut head[range])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for SplitEveryRef<&str, Pattern> {}
+impl<T, Pattern: ArrPattern<T>> FusedIterator for SplitEveryRef<&[T], Pattern> {}
+impl<T, Pattern: ArrPattern<T>> FusedIterator for SplitEveryRef<&mut [T], Pattern> {}
+
+impl<Pattern: Debug> Debug for SplitEveryRef<&str, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryRef", f)
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEveryRef<&[T], Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryRef", f)
+    }
+}
+
+impl<T: Debug, Pattern: Debug> Debug for SplitEveryRef<&mut [T], Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryRef", f)
+    }
+}
+
+impl<Input, Pattern> SplitEveryRef<Input, Pattern> {
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(self, max_splits: usize) -> Self {
+        SplitEveryRef(self.0.with_max_splits(max_splits))
+    }
+
+    /// Keeps every pattern so the chunks cover the whole input, see [`SplitEvery::lossless`].
+    pub fn lossless(self, lossless: bool) -> Self {
+        SplitEveryRef(self.0.lossless(lossless))
+    }
+
+    /// Counts overlapping occurrences of the pattern, see [`SplitEvery::overlapping`].
+    pub fn overlapping(self, overlapping: bool) -> Self {
+        SplitEveryRef(self.0.overlapping(overlapping))
+    }
+
+    /// Drops empty chunks, see [`SplitEvery::skip_empty`].
+    pub fn skip_empty(self, skip_empty: bool) -> Self {
+        SplitEveryRef(self.0.skip_empty(skip_empty))
+    }
+
+    /// Yields a final empty chunk after a trailing pattern, see
+    /// [`SplitEvery::keep_trailing_empty`].
+    pub fn keep_trailing_empty(self, keep_trailing_empty: bool) -> Self {
+        SplitEveryRef(self.0.keep_trailing_empty(keep_trailing_empty))
+    }
+
+    /// Trims whitespace off each string chunk, see [`SplitEvery::trim_chunks`].
+    pub fn trim_chunks(self, trim: bool) -> Self {
+        SplitEveryRef(self.0.trim_chunks(trim))
+    }
+
+    /// Yields nothing for input lacking the pattern, see [`SplitEvery::require_pattern`].
+    pub fn require_pattern(self, require_pattern: bool) -> Self {
+        SplitEveryRef(self.0.require_pattern(require_pattern))
+    }
+
+    /// Merges chunks shorter than `min_chunk_len`, see [`SplitEvery::min_chunk_len`].
+    pub fn min_chunk_len(self, min_chunk_len: usize) -> Self {
+        SplitEveryRef(self.0.min_chunk_len(min_chunk_len))
+    }
+
+    /// Pairs each chunk with the remainder left after it, which is empty after the last
+    /// chunk.
+    ///
+    /// ```rust
+    /// use split_every::prelude::*;
+    ///
+    /// let mut splitter = "a,b,c".split_every_n_times_ref(",", 1).with_remainders();
+    /// assert_eq!(splitter.next(), Some(("a", "b,c")));
+    /// assert_eq!(splitter.next(), Some(("b", "c")));
+    /// assert_eq!(splitter.next(), Some(("c", "")));
+    /// ```
+    pub fn with_remainders(self) -> SplitEveryRemainders<Input, Pattern> {
+        SplitEveryRemainders(self)
+    }
+
+    /// Consumes the iterator, returning the original input, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        self.0.into_inner()
+    }
+}
+
+impl<'a, Pattern> SplitEveryRef<&'a str, Pattern> {
+    /// Returns the part of the input that has not been split yet.
+    ///
+    /// Once the iterator is exhausted, this is an empty string.
+    pub fn remainder(&self) -> &'a str {
+        self.0.remainder()
+    }
+
+    /// Restarts iteration from the beginning of the input.
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    /// Returns the byte offset where the unsplit input starts, which is the input's length
+    /// once exhausted.
+    ///
+    /// Chunks taken from the back do not move it.
+    pub fn byte_offset(&self) -> usize {
+        self.0.byte_offset()
+    }
+
+    /// Resumes splitting from byte `offset`, as returned by `byte_offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not on a char boundary or is past the unsplit input.
+    pub fn seek_to(&mut self, offset: usize) {
+        self.0.seek_to(offset);
+    }
+}
+
+impl<'a, T, Pattern> SplitEveryRef<&'a [T], Pattern> {
+    /// Returns the part of the input that has not been split yet.
+    ///
+    /// Once the iterator is exhausted, this is an empty slice.
+    pub fn remainder(&self) -> &'a [T] {
+        self.0.remainder()
+    }
+
+    /// Restarts iteration from the beginning of the input.
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    /// Returns the index where the unsplit input starts, which is the input's length once
+    /// exhausted.
+    ///
+    /// Chunks taken from the back do not move it.
+    pub fn index(&self) -> usize {
+        self.0.index()
+    }
+
+    /// Resumes splitting from index `offset`, as returned by `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the unsplit input.
+    pub fn seek_to(&mut self, offset: usize) {
+        self.0.seek_to(offset);
+    }
+}
+
+/// Like [`SplitEveryRef`], but pairs each chunk with the remainder left after it.
+///
+/// Created by [`SplitEveryRef::with_remainders`].
+#[derive(Clone)]
+pub struct SplitEveryRemainders<Input, Pattern>(SplitEveryRef<Input, Pattern>);
+
+impl<'a, Pattern: StrPattern> Iterator for SplitEveryRemainders<&'a str, Pattern> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: &'a str = self.0.next()?;
+        Some((chunk, self.0.remainder()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T, Pattern: ArrPattern<T>> Iterator for SplitEveryRemainders<&'a [T], Pattern> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: &'a [T] = self.0.next()?;
+        Some((chunk, self.0.remainder()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for SplitEveryRemainders<&str, Pattern> {}
+impl<T, Pattern: ArrPattern<T>> FusedIterator for SplitEveryRemainders<&[T], Pattern> {}
+
+impl<Input, Pattern> Debug for SplitEveryRemainders<Input, Pattern>
+where
+    SplitEveryRef<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SplitEveryRemainders")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/// Like [`SplitEvery`], but yields the range of each chunk within the input.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "SplitEvery<Input, Pattern>: serde::Deserialize<'de>"))
+)]
+pub struct SplitEveryRanges<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: StrPattern> Iterator for SplitEveryRanges<&str, Pattern> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_str_helper(&mut self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> Iterator for SplitEveryRanges<String, Pattern> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_str_helper(&mut self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T, Pattern: ArrPattern<T>> Iterator for SplitEveryRanges<Vec<T>, Pattern> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_arr_helper(&mut self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T, Pattern: ArrPattern<T>> Iterator for SplitEveryRanges<&[T], Pattern> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_next_arr_helper(&mut self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: StrPattern> DoubleEndedIterator for SplitEveryRanges<&str, Pattern> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        split_every_next_back_str_helper(&mut self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> DoubleEndedIterator for SplitEveryRanges<String, Pattern> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        split_every_next_back_str_helper(&mut self.0)
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for SplitEveryRanges<&str, Pattern> {}
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> FusedIterator for SplitEveryRanges<String, Pattern> {}
+impl<T, Pattern: ArrPattern<T>> FusedIterator for SplitEveryRanges<Vec<T>, Pattern> {}
+impl<T, Pattern: ArrPattern<T>> FusedIterator for SplitEveryRanges<&[T], Pattern> {}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryRanges<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryRanges", f)
+    }
+}
+
+/// Which delimiter ended a chunk of [`SplitEveryBreak`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SplitEveryTrigger {
+    /// The `n`th occurrence of the counted pattern.
+    Count,
+    /// An occurrence of the break pattern.
+    Break,
+    /// The end of the input.
+    End,
+}
+
+/// An iterator splitting every `n` occurrences of a pattern, or at once at a break pattern.
+///
+/// Created by [`SplitEveryImpl::split_every_n_with_break`].
+#[derive(Clone, Debug)]
+pub struct SplitEveryBreak<Input, Pattern, Break> {
+    input: Input,
+    pat: Pattern,
+    n: usize,
+    brk: Break,
+    ind: usize,
+}
+
+impl<Pattern: StrPattern, Break: StrPattern> Iterator for SplitEveryBreak<&str, Pattern, Break> {
+    type Item = (String, SplitEveryTrigger);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ind >= self.input.len() {
+            return None;
+        }
+        let start: usize = self.ind;
+        let rest = |from: usize| unsafe { self.input.get_unchecked(from..) };
+        let mut brk: Option<Range<usize>> =
+            split_every_find_checked_str_helper(&self.brk, rest(start))
+                .map(|found| found.start + start..found.end + start);
+        let mut from: usize = start;
+        let mut found: usize = 0;
+        let (end, trigger): (Range<usize>, SplitEveryTrigger) = loop {
+            if brk.as_ref().is_some_and(|brk| brk.start < from) {
+                brk = split_every_find_checked_str_helper(&self.brk, rest(from))
+                    .map(|found| found.start + from..found.end + from);
+            }
+            let pat: Option<Range<usize>> = if self.n == 0 {
+                None
+            } else {
+                split_every_find_checked_str_helper(&self.pat, rest(from))
+                    .map(|found| found.start + from..found.end + from)
+                    .filter(|pat| brk.as_ref().is_none_or(|brk| pat.start < brk.start))
+            };
+            match (pat, &brk) {
+                (Some(pat), _) => {
+                    found += 1;
+                    if found == self.n {
+                        break (pat, SplitEveryTrigger::Count);
+                    }
+                    from = pat.end;
+                }
+                (None, Some(brk)) => break (brk.clone(), SplitEveryTrigger::Break),
+                (None, None) => {
+                    let len: usize = self.input.len();
+                    break (len..len, SplitEveryTrigger::End);
+                }
+            }
+        };
+        self.ind = end.end;
+        Some((
+            unsafe { self.input.get_unchecked(start..end.start) }.to_string(),
+            trigger,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.input.len() - self.ind;
+        (usize::from(remaining != 0), Some(remaining))
+    }
+}
+
+impl<Pattern: StrPattern, Break: StrPattern> FusedIterator
+    for SplitEveryBreak<&str, Pattern, Break>
+{
+}
+
+/// An iterator splitting text every `n` words, wrapped to a maximum width.
+///
+/// Created by [`SplitEveryImpl::split_every_n_words_wrapped`].
+#[derive(Clone, Debug)]
+pub struct SplitEveryWrapped<Input> {
+    input: Input,
+    n: usize,
+    max_width: usize,
+    ind: usize,
+}
+
+impl Iterator for SplitEveryWrapped<&str> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, _, _, _) = split_every_next_word_helper(self.input, self.ind)?;
+        let mut end: usize = start;
+        let mut width: usize = 0;
+        let mut words: usize = 0;
+        while let Some((word_start, word_end, gap, word)) =
+            split_every_next_word_helper(self.input, end)
+        {
+            let gap: usize = if words == 0 { 0 } else { gap };
+            if words != 0 && width + gap + word > self.max_width {
+                break;
+            }
+            debug_assert!(words != 0 || word_start == start);
+            end = word_end;
+            width += gap + word;
+            words += 1;
+            if words == self.n {
+                break;
+            }
+        }
+        self.ind = end;
+        Some(unsafe { self.input.get_unchecked(start..end) }.to_string())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.input.len() - self.ind;
+        (0, Some(remaining.div_ceil(2)))
+    }
+}
+
+impl FusedIterator for SplitEveryWrapped<&str> {}
+
+/// Finds the first word at or after byte `from`.
+///
+/// Returns its byte range along with the char counts of the whitespace before it and of
+/// the word itself.
+fn split_every_next_word_helper(input: &str, from: usize) -> Option<(usize, usize, usize, usize)> {
+    let rest: &str = unsafe { input.get_unchecked(from..) };
+    let mut chars = rest.char_indices().peekable();
+    let mut gap: usize = 0;
+    let start: usize = loop {
+        let (ind, val) = chars.next()?;
+        if !val.is_whitespace() {
+            break ind;
+        }
+        gap += 1;
+    };
+    let mut word: usize = 1;
+    let mut end: usize = rest.len();
+    for (ind, val) in chars {
+        if val.is_whitespace() {
+            end = ind;
+            break;
+        }
+        word += 1;
+    }
+    Some((from + start, from + end, gap, word))
+}
+
+/// Like [`SplitEvery`], but yields each byte chunk decoded as UTF-8.
+///
+/// Created by [`SplitEveryImpl::split_every_n_times_utf8`].
+#[derive(Clone)]
+pub struct SplitEveryUtf8<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: ArrPattern<u8>> Iterator for SplitEveryUtf8<&[u8], Pattern> {
+    type Item = Result<String, Utf8Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(core::str::from_utf8(unsafe { self.0.input.get_unchecked(range) }).map(String::from))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: ArrPattern<u8>> Iterator for SplitEveryUtf8<Vec<u8>, Pattern> {
+    type Item = Result<String, Utf8Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(core::str::from_utf8(unsafe { self.0.input.get_unchecked(range) }).map(String::from))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: ArrPattern<u8>> FusedIterator for SplitEveryUtf8<&[u8], Pattern> {}
+impl<Pattern: ArrPattern<u8>> FusedIterator for SplitEveryUtf8<Vec<u8>, Pattern> {}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryUtf8<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryUtf8", f)
+    }
+}
+
+/// Like [`SplitEvery`], but groups chunks counting from the end of the input.
+///
+/// Chunks are yielded right to left. Mirroring [`SplitEvery`], input ending with a
+/// pattern yields an empty first chunk, while input starting with the `n`th pattern
+/// from the end yields no empty last chunk.
+///
+/// Created by [`SplitEveryImpl::rsplit_every_n_times`].
+#[derive(Clone)]
+pub struct RSplitEvery<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: StrPattern> Iterator for RSplitEvery<&str, Pattern> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_rnext_str_helper(&mut self.0)?;
+        Some(unsafe { self.0.input.get_unchecked(range) }.to_string())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for RSplitEvery<&[T], Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_rnext_arr_helper(&mut self.0)?;
+        Some(unsafe { self.0.input.get_unchecked(range) }.to_vec())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for RSplitEvery<&str, Pattern> {}
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for RSplitEvery<&[T], Pattern> {}
+
+impl<Input: Debug, Pattern: Debug> Debug for RSplitEvery<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "RSplitEvery", f)
+    }
+}
+
+/// Returns the byte range of the next chunk counting from the end of `input`.
+///
+/// Steps back over the last `n` occurrences with [`StrPattern::rfind_in`], so only the
+/// chunk being yielded is scanned.
+fn split_every_rnext_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    if split_every.finished {
+        return None;
+    }
+    let input: &str = split_every.input.as_ref();
+    let remaining: Range<usize> = split_every.remaining_range(input.len());
+    let haystack: &str = &input[remaining.clone()];
+    if split_every.n == 0 {
+        return split_every_end_helper(split_every, remaining);
+    }
+    // The chunk starts after the `n`th occurrence from the end.
+    let mut split: Range<usize> = haystack.len()..haystack.len();
+    for _ in 0..split_every.n {
+        let Some(found) = split_every_rfind_checked_str_helper(&split_every.pat, unsafe {
+            haystack.get_unchecked(..split.start)
+        }) else {
+            return split_every_end_helper(split_every, remaining);
+        };
+        split = found;
+    }
+    split_every.back = input.len() - remaining.start - split.start;
+    Some(remaining.start + split.end..remaining.end)
+}
+
+/// Returns the index range of the next chunk counting from the end of `input`.
+///
+/// Steps back over the last `n` occurrences with [`ArrPattern::rfind_in`], so only the
+/// chunk being yielded is scanned.
+fn split_every_rnext_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    if split_every.finished {
+        return None;
+    }
+    let input: &[T] = split_every.input.as_ref();
+    let remaining: Range<usize> = split_every.remaining_range(input.len());
+    let haystack: &[T] = &input[remaining.clone()];
+    if split_every.n == 0 {
+        return split_every_end_helper(split_every, remaining);
+    }
+    // The chunk starts after the `n`th occurrence from the end.
+    let mut split: Range<usize> = haystack.len()..haystack.len();
+    for _ in 0..split_every.n {
+        let Some(found) = split_every_rfind_checked_arr_helper(&mut split_every.pat, unsafe {
+            haystack.get_unchecked(..split.start)
+        }) else {
+            return split_every_end_helper(split_every, remaining);
+        };
+        split = found;
+    }
+    split_every.back = input.len() - remaining.start - split.start;
+    Some(remaining.start + split.end..remaining.end)
+}
+
+impl<Input, Pattern> SplitEveryRanges<Input, Pattern> {
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(self, max_splits: usize) -> Self {
+        SplitEveryRanges(self.0.with_max_splits(max_splits))
+    }
+
+    /// Keeps every pattern so the ranges cover the whole input, see [`SplitEvery::lossless`].
+    pub fn lossless(self, lossless: bool) -> Self {
+        SplitEveryRanges(self.0.lossless(lossless))
+    }
+
+    /// Counts overlapping occurrences of the pattern, see [`SplitEvery::overlapping`].
+    pub fn overlapping(self, overlapping: bool) -> Self {
+        SplitEveryRanges(self.0.overlapping(overlapping))
+    }
+
+    /// Drops empty ranges, see [`SplitEvery::skip_empty`].
+    pub fn skip_empty(self, skip_empty: bool) -> Self {
+        SplitEveryRanges(self.0.skip_empty(skip_empty))
+    }
+
+    /// Consumes the iterator, returning the original input, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        self.0.into_inner()
+    }
+
+    /// Restarts iteration from the beginning of the input.
+    pub fn reset(&mut self) {
+        self.0.rewind();
+    }
+}
+
+/// Like [`SplitEvery`], but also yields how many patterns each chunk consumed.
+///
+/// Patterns wholly inside a chunk count too, so only the final chunk may report fewer than
+/// `n`. When `n` is `0` or the chunk limit is reached, the remaining input reports `0`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "SplitEvery<Input, Pattern>: serde::Deserialize<'de>"))
+)]
+pub struct SplitEveryCounted<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: StrPattern> Iterator for SplitEveryCounted<&str, Pattern> {
+    type Item = (String, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, count) = split_every_next_str_counted_helper(&mut self.0)?;
+        Some((
+            unsafe { self.0.input.get_unchecked(range) }.to_string(),
+            count,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> Iterator for SplitEveryCounted<String, Pattern> {
+    type Item = (String, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, count) = split_every_next_str_counted_helper(&mut self.0)?;
+        Some((
+            unsafe { self.0.input.get_unchecked(range) }.to_string(),
+            count,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryCounted<Vec<T>, Pattern> {
+    type Item = (Vec<T>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, count) = split_every_next_arr_counted_helper(&mut self.0)?;
+        Some((unsafe { self.0.input.get_unchecked(range) }.to_vec(), count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryCounted<&[T], Pattern> {
+    type Item = (Vec<T>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, count) = split_every_next_arr_counted_helper(&mut self.0)?;
+        Some((unsafe { self.0.input.get_unchecked(range) }.to_vec(), count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for SplitEveryCounted<&str, Pattern> {}
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> FusedIterator for SplitEveryCounted<String, Pattern> {}
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEveryCounted<Vec<T>, Pattern> {}
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEveryCounted<&[T], Pattern> {}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryCounted<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryCounted", f)
+    }
+}
+
+impl<Input, Pattern> SplitEveryCounted<Input, Pattern> {
+    /// Limits the iterator to at most `max_splits` chunks, like [`str::splitn`].
+    ///
+    /// Once `max_splits - 1` chunks have been yielded, all remaining input is
+    /// yielded as the final chunk, regardless of how many patterns it contains.
+    pub fn with_max_splits(self, max_splits: usize) -> Self {
+        SplitEveryCounted(self.0.with_max_splits(max_splits))
+    }
+
+    /// Consumes the iterator, returning the original input, pattern, and `n`.
+    pub fn into_inner(self) -> (Input, Pattern, usize) {
+        self.0.into_inner()
+    }
+}
+
+/// Like [`SplitEvery`], but pairs each chunk with the running number of patterns consumed.
+///
+/// Created by [`SplitEvery::enumerate_chunks`].
+#[derive(Clone)]
+pub struct SplitEveryEnumerate<Input, Pattern> {
+    inner: SplitEveryCounted<Input, Pattern>,
+    total: usize,
+}
+
+impl<Input, Pattern, Chunk> Iterator for SplitEveryEnumerate<Input, Pattern>
+where
+    SplitEveryCounted<Input, Pattern>: Iterator<Item = (Chunk, usize)>,
+{
+    type Item = (usize, Chunk);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (chunk, count) = self.inner.next()?;
+        self.total = unsafe { self.total.unchecked_add(count) };
+        Some((self.total, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<Input, Pattern, Chunk> FusedIterator for SplitEveryEnumerate<Input, Pattern> where
+    SplitEveryCounted<Input, Pattern>: FusedIterator<Item = (Chunk, usize)>
+{
+}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryEnumerate<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEveryEnumerate")
+            .field("inner", &self.inner)
+            .field("total", &self.total)
+            .finish()
+    }
+}
+
+/// Like [`SplitEvery`], but each chunk starts over the last segments of the one before.
+///
+/// Created by [`SplitEvery::window_overlap`].
+#[derive(Clone)]
+pub struct SplitEveryWindows<Input, Pattern> {
+    inner: SplitEvery<Input, Pattern>,
+    overlap: usize,
+}
+
+impl<Pattern: StrPattern> Iterator for SplitEveryWindows<&str, Pattern> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input: &str = self.inner.input;
+        let range: Range<usize> =
+            split_every_window_helper(&mut self.inner, self.overlap, input.len(), |pat, from| {
+                split_every_find_checked_str_helper(pat, unsafe { input.get_unchecked(from..) })
+            })?;
+        Some(unsafe { input.get_unchecked(range) }.to_string())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryWindows<&[T], Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input: &[T] = self.inner.input;
+        let range: Range<usize> =
+            split_every_window_helper(&mut self.inner, self.overlap, input.len(), |pat, from| {
+                split_every_find_checked_arr_helper(pat, unsafe { input.get_unchecked(from..) })
+            })?;
+        Some(unsafe { input.get_unchecked(range) }.to_vec())
+    }
+}
+
+impl<Pattern: StrPattern> FusedIterator for SplitEveryWindows<&str, Pattern> {}
+
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEveryWindows<&[T], Pattern> {}
+
+impl<Input, Pattern> Debug for SplitEveryWindows<Input, Pattern>
+where
+    SplitEvery<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEveryWindows")
+            .field("inner", &self.inner)
+            .field("overlap", &self.overlap)
+            .finish()
+    }
+}
+
+/// Finds the next window of `split_every`, moving it back over the last `overlap` segments.
+///
+/// `find` searches the input from the given offset, returning a match relative to it.
+fn split_every_window_helper<Input, Pattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    overlap: usize,
+    input_len: usize,
+    mut find: impl FnMut(&mut Pattern, usize) -> Option<Range<usize>>,
+) -> Option<Range<usize>> {
+    if split_every.finished || split_every.ind >= input_len {
+        split_every.finished = true;
+        return None;
+    }
+    let start: usize = split_every.ind;
+    let n: usize = split_every.n;
+    let keep: usize = n - overlap.min(n.saturating_sub(1));
+    let mut from: usize = start;
+    let mut restart: usize = start;
+    for found in 1..=n {
+        let Some(next) = find(&mut split_every.pat, from) else {
+            break;
+        };
+        let next: Range<usize> = from + next.start..from + next.end;
+        if found == keep {
+            restart = next.end;
+        }
+        if found == n {
+            split_every.ind = restart;
+            split_every.finished = next.end == input_len;
+            return Some(start..next.start);
+        }
+        from = next.end;
+    }
+    split_every.finished = true;
+    Some(start..input_len)
+}
+
+/// Like [`SplitEvery`], but removes the patterns left inside each chunk.
+///
+/// Created by [`SplitEvery::drop_interior_delimiters`].
+#[derive(Clone)]
+pub struct SplitEveryDropInterior<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: StrPattern> Iterator for SplitEveryDropInterior<&str, Pattern> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        Some(split_every_strip_str_helper(&self.0.pat, unsafe {
+            self.0.input.get_unchecked(range)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> Iterator for SplitEveryDropInterior<String, Pattern> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        Some(split_every_strip_str_helper(&self.0.pat, unsafe {
+            self.0.input.get_unchecked(range)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryDropInterior<Vec<T>, Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(split_every_strip_arr_helper(&mut self.0.pat, unsafe {
+            self.0.input.get_unchecked(range)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, const N: usize, Pattern: ArrPattern<T>> Iterator
+    for SplitEveryDropInterior<[T; N], Pattern>
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(split_every_strip_arr_helper(&mut self.0.pat, unsafe {
+            self.0.input.get_unchecked(range)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryDropInterior<&[T], Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        Some(split_every_strip_arr_helper(&mut self.0.pat, unsafe {
+            self.0.input.get_unchecked(range)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Input: FnMut() -> Option<Pattern>, Pattern: PartialEq> Iterator
+    for SplitEveryDropInterior<Input, Pattern>
+{
+    type Item = Vec<Pattern>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk: Vec<Pattern> = split_every_next_fn_helper::<_, _, _, Vec<_>>(
+            &mut self.0,
+            |input| input(),
+            |pat, val| val == pat,
+        )?;
+        chunk.retain(|val| *val != self.0.pat);
+        Some(chunk)
+    }
+}
+
+impl<Input, Pattern> FusedIterator for SplitEveryDropInterior<Input, Pattern>
+where
+    SplitEveryDropInterior<Input, Pattern>: Iterator,
+    SplitEvery<Input, Pattern>: FusedIterator,
+{
+}
+
+impl<Input, Pattern> Debug for SplitEveryDropInterior<Input, Pattern>
+where
+    SplitEvery<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SplitEveryDropInterior")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/// Copies `chunk` without the occurrences of `pat` in it.
+fn split_every_strip_str_helper<Pattern: StrPattern>(pat: &Pattern, chunk: &str) -> String {
+    let mut out: String = String::with_capacity(chunk.len());
+    let mut from: usize = 0;
+    while let Some(found) =
+        split_every_find_checked_str_helper(pat, unsafe { chunk.get_unchecked(from..) })
+    {
+        out.push_str(unsafe { chunk.get_unchecked(from..from.unchecked_add(found.start)) });
+        from = unsafe { from.unchecked_add(found.end) };
+    }
+    out.push_str(unsafe { chunk.get_unchecked(from..) });
+    out
+}
+
+/// Copies `chunk` without the occurrences of `pat` in it.
+fn split_every_strip_arr_helper<T: Clone, Pattern: ArrPattern<T>>(
+    pat: &mut Pattern,
+    chunk: &[T],
+) -> Vec<T> {
+    let mut out: Vec<T> = Vec::with_capacity(chunk.len());
+    let mut from: usize = 0;
+    while let Some(found) =
+        split_every_find_checked_arr_helper(pat, unsafe { chunk.get_unchecked(from..) })
+    {
+        out.extend_from_slice(unsafe {
+            chunk.get_unchecked(from..from.unchecked_add(found.start))
+        });
+        from = unsafe { from.unchecked_add(found.end) };
+    }
+    out.extend_from_slice(unsafe { chunk.get_unchecked(from..) });
+    out
+}
+
+/// Like [`SplitEvery`], but pairs each chunk with its range within the input.
+///
+/// Created by [`SplitEvery::chunks_and_positions`].
+#[derive(Clone)]
+pub struct SplitEveryPositions<Input, Pattern>(SplitEvery<Input, Pattern>);
+
+impl<Pattern: StrPattern> Iterator for SplitEveryPositions<&str, Pattern> {
+    type Item = (Range<usize>, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        let chunk: String = unsafe { self.0.input.get_unchecked(range.clone()) }.to_string();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> Iterator for SplitEveryPositions<String, Pattern> {
+    type Item = (Range<usize>, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        let chunk: String = unsafe { self.0.input.get_unchecked(range.clone()) }.to_string();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern> Iterator for SplitEveryPositions<Cow<'_, str>, Pattern> {
+    type Item = (Range<usize>, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.0)?;
+        let chunk: String = unsafe { self.0.input.get_unchecked(range.clone()) }.to_string();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryPositions<Vec<T>, Pattern> {
+    type Item = (Range<usize>, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        let chunk: Vec<T> = unsafe { self.0.input.get_unchecked(range.clone()) }.to_vec();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, const N: usize, Pattern: ArrPattern<T>> Iterator
+    for SplitEveryPositions<[T; N], Pattern>
+{
+    type Item = (Range<usize>, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        let chunk: Vec<T> = unsafe { self.0.input.get_unchecked(range.clone()) }.to_vec();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEveryPositions<&[T], Pattern> {
+    type Item = (Range<usize>, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.0)?;
+        let chunk: Vec<T> = unsafe { self.0.input.get_unchecked(range.clone()) }.to_vec();
+        Some((range, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.0, self.0.input.len())
+    }
+}
+
+impl<Input, Pattern> FusedIterator for SplitEveryPositions<Input, Pattern>
+where
+    SplitEveryPositions<Input, Pattern>: Iterator,
+    SplitEvery<Input, Pattern>: FusedIterator,
+{
+}
+
+impl<Input: Debug, Pattern: Debug> Debug for SplitEveryPositions<Input, Pattern> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        split_every_fmt_helper(&self.0, "SplitEveryPositions", f)
+    }
+}
+
+/// Like [`SplitEvery`], but applies a function to each chunk borrowed from the input.
+///
+/// Created by [`SplitEvery::map_chunks`].
+#[derive(Clone)]
+pub struct SplitEveryMap<Input, Pattern, F> {
+    inner: SplitEvery<Input, Pattern>,
+    f: F,
+}
+
+/// The iterator returned by
+/// [`split_every_n_times_parse`](SplitEveryImpl::split_every_n_times_parse).
+pub type SplitEveryParse<Input, Pattern, T> =
+    SplitEveryMap<Input, Pattern, fn(&str) -> Result<T, <T as FromStr>::Err>>;
+
+/// The iterator returned by
+/// [`split_every_n_times_boxed`](SplitEveryImpl::split_every_n_times_boxed).
+pub type SplitEveryBoxed<Input, Pattern> = SplitEveryMap<Input, Pattern, fn(&str) -> Box<str>>;
+
+impl<'a, Pattern: StrPattern, F: FnMut(&'a str) -> R, R> Iterator
+    for SplitEveryMap<&'a str, Pattern, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern, F: FnMut(&str) -> R, R> Iterator for SplitEveryMap<String, Pattern, F> {
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: StrPattern, F: FnMut(&str) -> R, R> Iterator
+    for SplitEveryMap<Cow<'_, str>, Pattern, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_str_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+impl<T, Pattern: ArrPattern<T>, F: FnMut(&[T]) -> R, R> Iterator
+    for SplitEveryMap<Vec<T>, Pattern, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+impl<T, const N: usize, Pattern: ArrPattern<T>, F: FnMut(&[T]) -> R, R> Iterator
+    for SplitEveryMap<[T; N], Pattern, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+impl<'a, T, Pattern: ArrPattern<T>, F: FnMut(&'a [T]) -> R, R> Iterator
+    for SplitEveryMap<&'a [T], Pattern, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(&mut self.inner)?;
+        Some((self.f)(unsafe { self.inner.input.get_unchecked(range) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(&self.inner, self.inner.input.len())
+    }
+}
+
+impl<Input, Pattern, F, R> FusedIterator for SplitEveryMap<Input, Pattern, F>
+where
+    SplitEveryMap<Input, Pattern, F>: Iterator<Item = R>,
+    SplitEvery<Input, Pattern>: FusedIterator,
+{
+}
+
+impl<Input, Pattern, F> Debug for SplitEveryMap<Input, Pattern, F>
+where
+    SplitEvery<Input, Pattern>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitEveryMap")
+            .field("inner", &self.inner)
+            .field("f", &format_args!("<fn>"))
+            .finish()
+    }
+}
+
+/// Returns the byte range of the next chunk within `input`.
+///
+/// The range is within the checked [`SplitEvery::unsplit_range`] and on char boundaries,
+/// so callers slice `input` with it unchecked.
+fn split_every_next_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    split_every_next_str_counted_helper(split_every).map(|(range, _)| range)
+}
+
+/// Like `split_every_next_str_helper`, but also returns how many patterns were found.
+///
+/// Patterns consumed by skipped empty chunks are added to those of the next chunk.
+fn split_every_next_str_counted_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<(Range<usize>, usize)> {
+    let next: Option<(Range<usize>, usize)> =
+        split_every_skip_empty_helper(split_every, |split_every| {
+            split_every_coalesce_helper(split_every, |split_every| {
+                split_every_next_str_chunk_helper(split_every)
+                    .map(|(range, count)| (split_every_trim_str_helper(split_every, range), count))
+            })
+        });
+    debug_assert!(next.as_ref().is_none_or(|(range, _)| {
+        split_every_char_range_helper(split_every.input.as_ref(), range)
+    }));
+    next
+}
+
+fn split_every_next_str_chunk_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<(Range<usize>, usize)> {
+    if split_every.finished || split_every.max_splits == Some(0) {
+        split_every.finished = true;
+        return None;
+    }
+    let is_whole: bool = !split_every.take_chunk();
+    let input: &str = split_every.input.as_ref();
+    let Range { start, end } = split_every.unsplit_range(input.len());
+    let iter_haystack: &str = &input[start..end];
+    if is_whole {
+        return split_every_end_str_helper(split_every, start..end).map(|range| (range, 0));
+    }
+    if split_every.n == 1 && !split_every.overlapping {
+        // Each chunk ends at the next occurrence, so skip the counting loop below.
+        let Some(split) = split_every_find_checked_str_helper(&split_every.pat, iter_haystack)
+        else {
+            return split_every_end_str_helper(split_every, start..end).map(|range| (range, 0));
+        };
+        split_every.ind = unsafe { start.unchecked_add(split.end) };
+        let chunk_end: usize = if split_every.keeps_pattern() {
+            split.end
+        } else {
+            split.start
+        };
+        return Some((start..unsafe { start.unchecked_add(chunk_end) }, 1));
+    }
+    let mut split: Range<usize> = 0..0;
+    let mut from: usize = 0;
+    for found_count in 0..split_every.n {
+        debug_assert!(iter_haystack.is_char_boundary(from));
+        let haystack: &str = unsafe { iter_haystack.get_unchecked(from..) };
+        match split_every_find_checked_str_helper(&split_every.pat, haystack) {
+            Some(found) => {
+                split = unsafe { from.unchecked_add(found.start)..from.unchecked_add(found.end) };
+                from = if split_every.overlapping {
+                    split_every_overlap_str_helper(iter_haystack, &split)
+                } else {
+                    split.end
+                };
+            }
+            None => {
+                return split_every_end_str_helper(split_every, start..end)
+                    .map(|range| (range, found_count))
+            }
+        }
+    }
+    split_every.ind = unsafe { start.unchecked_add(split.end) };
+    if split_every.keeps_pattern() {
+        return Some((start..split_every.ind, split_every.n));
+    }
+    Some((
+        start..unsafe { start.unchecked_add(split.start) },
+        split_every.n,
+    ))
+}
+
+/// Returns the byte range `split_every_next_str_helper` would return, without advancing.
+fn split_every_peek_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    split_every_next_str_helper(
+        &mut split_every.with_parts(split_every.input.as_ref(), Borrowed(&split_every.pat)),
+    )
+}
+
+/// Counts the ranges `split_every_next_str_helper` would still return, without advancing.
+fn split_every_chunks_remaining_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> usize {
+    let mut view: SplitEvery<&str, Borrowed<'_, Pattern>> =
+        split_every.with_parts(split_every.input.as_ref(), Borrowed(&split_every.pat));
+    core::iter::from_fn(|| split_every_next_str_helper(&mut view)).count()
+}
+
+/// Counts the occurrences of the pattern within the remaining input.
+fn split_every_occurrences_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> usize {
+    let input: &str = split_every.input.as_ref();
+    let haystack: &str = &input[split_every.remaining_range(input.len())];
+    let mut count: usize = 0;
+    let mut from: usize = 0;
+    while let Some(found) = split_every_find_checked_str_helper(&split_every.pat, unsafe {
+        haystack.get_unchecked(from..)
+    }) {
+        let split: Range<usize> =
+            unsafe { from.unchecked_add(found.start)..from.unchecked_add(found.end) };
+        count = unsafe { count.unchecked_add(1) };
+        from = if split_every.overlapping {
+            split_every_overlap_str_helper(haystack, &split)
+        } else {
+            split.end
+        };
+    }
+    count
+}
+
+fn split_every_next_into_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    buf: &mut String,
+) -> bool {
+    buf.clear();
+    let Some(range) = split_every_next_str_helper(split_every) else {
+        return false;
+    };
+    buf.push_str(unsafe { split_every.input.as_ref().get_unchecked(range) });
+    true
+}
+
+fn split_every_join_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    sep: &str,
+) -> String {
+    let mut out: String = String::new();
+    if let Some(range) = split_every_next_str_helper(split_every) {
+        out.push_str(unsafe { split_every.input.as_ref().get_unchecked(range) });
+    }
+    while let Some(range) = split_every_next_str_helper(split_every) {
+        out.push_str(sep);
+        out.push_str(unsafe { split_every.input.as_ref().get_unchecked(range) });
+    }
+    out
+}
+
+/// Searches with a borrowed [`StrPattern`], so that iteration state can be copied
+/// without the pattern.
+struct Borrowed<'a, Pattern>(&'a Pattern);
+
+impl<Pattern: StrPattern> StrPattern for Borrowed<'_, Pattern> {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        self.0.find_in(haystack)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        self.0.rfind_in(haystack)
+    }
+}
+
+/// Returns the byte range of the last chunk within `input`.
+///
+/// Chunk boundaries are anchored to the start of the input, so only the first call counts the
+/// remaining occurrences; later calls search backwards for `n` of them. Inclusive, overlapping
+/// and split-limited iterators still scan from the front to stay consistent with `next`.
+fn split_every_next_back_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    if split_every.min_chunk_len != 0 {
+        return split_every_next_back_coalesced_str_helper(split_every);
+    }
+    let next: Option<Range<usize>> = split_every_skip_empty_helper(split_every, |split_every| {
+        split_every_next_back_str_chunk_helper(split_every)
+            .map(|range| (split_every_trim_str_helper(split_every, range), 0))
+    })
+    .map(|(range, _)| range);
+    debug_assert!(next
+        .as_ref()
+        .is_none_or(|range| split_every_char_range_helper(split_every.input.as_ref(), range)));
+    next
+}
+
+/// Returns the last chunk `split_every_next_str_helper` would return, by splitting a copy of
+/// the remaining input from the front.
+///
+/// Merging chunks depends on the ones before them, so the last chunk can only be found from
+/// the front.
+fn split_every_next_back_coalesced_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    if split_every.finished || split_every.max_splits == Some(0) {
+        split_every.finished = true;
+        return None;
+    }
+    let input: &str = split_every.input.as_ref();
+    let mut view: SplitEvery<&str, Borrowed<'_, Pattern>> =
+        split_every.with_parts(input, Borrowed(&split_every.pat));
+    let mut last: Option<Range<usize>> = None;
+    let mut prev_end: Option<usize> = None;
+    while let Some(range) = split_every_next_str_helper(&mut view) {
+        prev_end = last.replace(range).map(|last| last.end);
+    }
+    let input_len: usize = input.len();
+    let Some(last) = last else {
+        split_every.finished = true;
+        return None;
+    };
+    let Some(prev_end) = prev_end else {
+        split_every.finished = true;
+        return Some(last);
+    };
+    // The rest ends with the previous chunk, which may be empty.
+    split_every.back = input_len - prev_end;
+    if !split_every.keeps_pattern() {
+        split_every.allow_trailing_empty = true;
+    }
+    if let Some(max_splits) = split_every.max_splits {
+        split_every.max_splits = Some(max_splits.saturating_sub(1));
+    }
+    Some(last)
+}
+
+/// Narrows `range` to exclude surrounding whitespace when `trim_chunks` is set.
+fn split_every_trim_str_helper<Input: AsRef<str>, Pattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+    range: Range<usize>,
+) -> Range<usize> {
+    if !split_every.trim {
+        return range;
+    }
+    let chunk: &str = unsafe { split_every.input.as_ref().get_unchecked(range.clone()) };
+    let trimmed: &str = chunk.trim_start();
+    let start: usize = range.end - trimmed.len();
+    start..start + trimmed.trim_end().len()
+}
+
+/// Whether `range` slices `input` on char boundaries, as every `get_unchecked` on it requires.
+fn split_every_char_range_helper(input: &str, range: &Range<usize>) -> bool {
+    range.start <= range.end
+        && range.end <= input.len()
+        && input.is_char_boundary(range.start)
+        && input.is_char_boundary(range.end)
+}
+
+fn split_every_next_back_str_chunk_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    if split_every.finished || split_every.max_splits == Some(0) {
+        split_every.finished = true;
+        return None;
+    }
+    let mut range: Range<usize> = split_every_back_str_helper(split_every)?;
+    if !split_every.keeps_pattern() && !split_every.allows_trailing_empty() {
+        split_every.allow_trailing_empty = true;
+        if range.is_empty() {
+            if split_every.finished {
+                return None;
+            }
+            range = split_every_back_str_helper(split_every)?;
+        }
+    }
+    if let Some(max_splits) = split_every.max_splits {
+        split_every.max_splits = Some(max_splits.saturating_sub(1));
+    }
+    Some(range)
+}
+
+fn split_every_back_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    let input: &str = split_every.input.as_ref();
+    let Range { start, end } = split_every.unsplit_range(input.len());
+    let iter_haystack: &str = &input[start..end];
+    if split_every.n != 0 && !split_every.keeps_pattern() && split_every.max_splits.is_none() {
+        let Some(split) = split_every_rsplit_str_helper(split_every, iter_haystack) else {
+            return split_every_end_str_helper(split_every, start..end);
+        };
+        split_every.back_aligned = true;
+        split_every.back = unsafe { input.len().unchecked_sub(start.unchecked_add(split.start)) };
+        return Some(unsafe { start.unchecked_add(split.end) }..end);
+    }
+    let mut last_split: Option<Range<usize>> = None;
+    let mut prev_split: Option<Range<usize>> = None;
+    // With a chunk limit, only the splits before the final chunk are counted.
+    let mut splits_left: usize = split_every
+        .max_splits
+        .map_or(usize::MAX, |max_splits| max_splits.saturating_sub(1));
+    if split_every.n != 0 && splits_left != 0 {
+        let mut len: usize = 0;
+        let mut count: usize = 0;
+        while let Some(found) = split_every_find_checked_str_helper(&split_every.pat, unsafe {
+            iter_haystack.get_unchecked(len..)
+        }) {
+            let split: Range<usize> =
+                unsafe { len.unchecked_add(found.start)..len.unchecked_add(found.end) };
+            count = unsafe { count.unchecked_add(1) };
+            len = if split_every.overlapping && count != split_every.n {
+                split_every_overlap_str_helper(iter_haystack, &split)
+            } else {
+                split.end
+            };
+            if count == split_every.n {
+                count = 0;
+                prev_split = last_split.replace(split);
+                splits_left = unsafe { splits_left.unchecked_sub(1) };
+                if splits_left == 0 {
+                    break;
+                }
+            }
+        }
+    }
+    // An inclusive chunk ending on the last pattern owns it, so nothing trails after it.
+    if split_every.keeps_pattern()
+        && last_split
+            .as_ref()
+            .is_some_and(|split| split.end == iter_haystack.len())
+    {
+        last_split = prev_split;
+    }
+    let Some(split) = last_split else {
+        return split_every_end_str_helper(split_every, start..end);
+    };
+    let chunk_start: usize = unsafe { start.unchecked_add(split.end) };
+    let new_end: usize = if split_every.keeps_pattern() {
+        chunk_start
+    } else {
+        unsafe { start.unchecked_add(split.start) }
+    };
+    split_every.back = unsafe { input.len().unchecked_sub(new_end) };
+    Some(chunk_start..end)
+}
+
+/// Returns the `n`th pattern before the last chunk within `haystack`, searching from its end.
+///
+/// Chunks are anchored to the front, so unless a previous call left the occurrences one
+/// short of a multiple of `n`, they are counted first to find how many the last chunk holds.
+fn split_every_rsplit_str_helper<Input, Pattern: StrPattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+    haystack: &str,
+) -> Option<Range<usize>> {
+    let steps: usize = if split_every.back_aligned {
+        split_every.n
+    } else {
+        let mut count: usize = 0;
+        let mut from: usize = 0;
+        while let Some(found) = split_every_find_checked_str_helper(&split_every.pat, unsafe {
+            haystack.get_unchecked(from..)
+        }) {
+            count = unsafe { count.unchecked_add(1) };
+            from = unsafe { from.unchecked_add(found.end) };
+        }
+        if count < split_every.n {
+            return None;
+        }
+        count % split_every.n + 1
+    };
+    let mut split: Range<usize> = haystack.len()..haystack.len();
+    for _ in 0..steps {
+        split = split_every_rfind_checked_str_helper(&split_every.pat, unsafe {
+            haystack.get_unchecked(..split.start)
+        })?;
+    }
+    Some(split)
+}
+
+/// Extends the range returned by `next_chunk` over the following ones while it is shorter
+/// than `min_chunk_len`.
+///
+/// Merged chunks are not counted against `max_splits`.
+fn split_every_coalesce_helper<Input, Pattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    mut next_chunk: impl FnMut(&mut SplitEvery<Input, Pattern>) -> Option<(Range<usize>, usize)>,
+) -> Option<(Range<usize>, usize)> {
+    let (mut range, mut consumed) = next_chunk(split_every)?;
+    while range.len() < split_every.min_chunk_len && !split_every.finished {
+        let max_splits: Option<usize> = split_every.max_splits;
+        // Lets the merged chunk split off normally even when it is the last one allowed.
+        split_every.max_splits = max_splits.map(|max_splits| max_splits.saturating_add(1));
+        let next: Option<(Range<usize>, usize)> = next_chunk(split_every);
+        split_every.max_splits = max_splits;
+        let Some((next, count)) = next else {
+            break;
+        };
+        range.end = next.end;
+        consumed = consumed.saturating_add(count);
+    }
+    Some((range, consumed))
+}
+
+/// Calls `next_chunk` until it returns a non-empty range when skipping empty chunks.
+///
+/// Skipped chunks are not counted against `max_splits`.
+fn split_every_skip_empty_helper<Input, Pattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    mut next_chunk: impl FnMut(&mut SplitEvery<Input, Pattern>) -> Option<(Range<usize>, usize)>,
+) -> Option<(Range<usize>, usize)> {
+    let mut consumed: usize = 0;
+    loop {
+        let max_splits: Option<usize> = split_every.max_splits;
+        let (range, count) = next_chunk(split_every)?;
+        consumed = consumed.saturating_add(count);
+        if !split_every.skip_empty || !range.is_empty() {
+            return Some((range, consumed));
+        }
+        split_every.max_splits = max_splits;
+    }
+}
+
+/// Returns where to resume searching for overlapping occurrences after `split`.
+fn split_every_overlap_str_helper(haystack: &str, split: &Range<usize>) -> usize {
+    let first: usize = unsafe { haystack.get_unchecked(split.start..) }
+        .chars()
+        .next()
+        .map_or(1, char::len_utf8);
+    unsafe { split.start.unchecked_add(first) }
+}
+
+/// Bounds the remaining chunks by assuming every chunk but the last consumes `n` patterns,
+/// each at least one byte / element long.
+fn split_every_size_hint_helper<Input, Pattern>(
+    split_every: &SplitEvery<Input, Pattern>,
+    input_len: usize,
+) -> (usize, Option<usize>) {
+    if split_every.finished || split_every.max_splits == Some(0) {
+        return (0, Some(0));
+    }
+    let remaining: usize = split_every.unsplit_range(input_len).len();
+    // Input lacking the pattern may still be rejected as a whole.
+    let may_reject: bool =
+        split_every.require_pattern && split_every.ind == 0 && split_every.back == 0;
+    if remaining == 0 {
+        let trailing: usize = usize::from(
+            split_every.allows_trailing_empty() && !split_every.skip_empty && !may_reject,
+        );
+        return (trailing, Some(trailing));
+    }
+    if split_every.n == 0 {
+        return (usize::from(!may_reject), Some(1));
+    }
+    let upper: usize = remaining / split_every.n + 1;
+    (
+        usize::from(!split_every.skip_empty && !may_reject),
+        Some(
+            split_every
+                .max_splits
+                .map_or(upper, |max_splits| upper.min(max_splits)),
+        ),
+    )
+}
+
+/// Like `split_every_end_helper`, but yields nothing for input rejected by
+/// `require_pattern`.
+fn split_every_end_str_helper<Input: AsRef<str>, Pattern: StrPattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    range: Range<usize>,
+) -> Option<Range<usize>> {
+    // Any chunk yielded earlier consumed a pattern, so only the whole input is checked.
+    if split_every.require_pattern
+        && range.start == 0
+        && split_every.back == 0
+        && split_every_find_checked_str_helper(&split_every.pat, unsafe {
+            split_every.input.as_ref().get_unchecked(range.clone())
+        })
+        .is_none()
+    {
+        split_every.finished = true;
+        return None;
+    }
+    split_every_end_helper(split_every, range)
+}
+
+/// Like `split_every_end_helper`, but yields nothing for input rejected by
+/// `require_pattern`.
+fn split_every_end_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    range: Range<usize>,
+) -> Option<Range<usize>> {
+    // Any chunk yielded earlier consumed a pattern, so only the whole input is checked.
+    if split_every.require_pattern
+        && range.start == 0
+        && split_every.back == 0
+        && split_every_find_checked_arr_helper(&mut split_every.pat, unsafe {
+            split_every.input.as_ref().get_unchecked(range.clone())
+        })
+        .is_none()
+    {
+        split_every.finished = true;
+        return None;
+    }
+    split_every_end_helper(split_every, range)
+}
+
+fn split_every_end_helper<Input, Pattern>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    range: Range<usize>,
+) -> Option<Range<usize>> {
+    split_every.finished = true;
+    if split_every.allows_trailing_empty() || !range.is_empty() {
+        return Some(range);
+    }
+    None
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEvery<Vec<T>, Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(self)?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+
+    fn count(mut self) -> usize {
+        core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).count()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            split_every_next_arr_helper(self)?;
+        }
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let range: Range<usize> =
+            core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).last()?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+}
+
+impl<T: Clone, const N: usize, Pattern: ArrPattern<T>> Iterator for SplitEvery<[T; N], Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(self)?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+
+    fn count(mut self) -> usize {
+        core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).count()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            split_every_next_arr_helper(self)?;
+        }
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let range: Range<usize> =
+            core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).last()?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEvery<&[T], Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(self)?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+
+    fn count(mut self) -> usize {
+        core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).count()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            split_every_next_arr_helper(self)?;
+        }
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let range: Range<usize> =
+            core::iter::from_fn(|| split_every_next_arr_helper(&mut self)).last()?;
+        Some(unsafe { self.input.get_unchecked(range) }.to_vec())
+    }
+}
+
+/// Yields chunks as [`bytes::Bytes`] sharing the input's buffer, without copying.
+#[cfg(feature = "bytes")]
+impl<Pattern: ArrPattern<u8>> Iterator for SplitEvery<bytes::Bytes, Pattern> {
+    type Item = bytes::Bytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range: Range<usize> = split_every_next_arr_helper(self)?;
+        Some(self.input.slice(range))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEvery<Vec<T>, Pattern> {}
+#[cfg(feature = "bytes")]
+impl<Pattern: ArrPattern<u8>> FusedIterator for SplitEvery<bytes::Bytes, Pattern> {}
+impl<T: Clone, const N: usize, Pattern: ArrPattern<T>> FusedIterator
+    for SplitEvery<[T; N], Pattern>
+{
+}
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEvery<&[T], Pattern> {}
+
+/// Splits the deque as one slice, making it contiguous the first time a chunk is taken.
+///
+/// The deque is never modified afterwards, so no elements are moved again, and patterns
+/// are found even where they straddle the end of the ring buffer.
+impl<T: Clone, Pattern: ArrPattern<T>> Iterator for SplitEvery<VecDeque<T>, Pattern> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_deque_helper(self, |view| {
+            let range: Range<usize> = split_every_next_arr_helper(view)?;
+            Some(unsafe { view.input.get_unchecked(range) }.to_vec())
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+}
+
+impl<T: Clone, Pattern: ArrPattern<T>> FusedIterator for SplitEvery<VecDeque<T>, Pattern> {}
+
+/// Splits the encoded bytes of an [`OsStr`] on the encoded bytes of an [`OsStr`] pattern.
+///
+/// Only patterns that are valid UTF-8 ever match, since splitting the platform encoding
+/// anywhere else could produce invalid [`OsStr`]s. Other patterns, like empty ones, leave
+/// the input whole.
+#[cfg(feature = "std")]
+impl<Pattern: AsRef<OsStr>> Iterator for SplitEvery<&OsStr, Pattern> {
+    type Item = OsString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_os_helper(self, |view| {
+            let range: Range<usize> = split_every_next_arr_helper(view)?;
+            // Chunks only end at the input's ends or next to a match, which is valid UTF-8.
+            Some(
+                unsafe { OsStr::from_encoded_bytes_unchecked(view.input.get_unchecked(range)) }
+                    .to_os_string(),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+}
+
+/// Splits like [`SplitEvery<&OsStr, Pattern>`].
+#[cfg(feature = "std")]
+impl<Pattern: AsRef<OsStr>> Iterator for SplitEvery<OsString, Pattern> {
+    type Item = OsString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        split_every_os_helper(self, |view| {
+            let range: Range<usize> = split_every_next_arr_helper(view)?;
+            Some(
+                unsafe { OsStr::from_encoded_bytes_unchecked(view.input.get_unchecked(range)) }
+                    .to_os_string(),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        split_every_size_hint_helper(self, self.input.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Pattern: AsRef<OsStr>> FusedIterator for SplitEvery<&OsStr, Pattern> {}
+#[cfg(feature = "std")]
+impl<Pattern: AsRef<OsStr>> FusedIterator for SplitEvery<OsString, Pattern> {}
+
+/// Runs `f` on a view of the input's encoded bytes, then keeps the state `f` left it in.
+#[cfg(feature = "std")]
+fn split_every_os_helper<Input: AsRef<OsStr>, Pattern: AsRef<OsStr>, R>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    f: impl FnOnce(&mut SplitEvery<&[u8], &[u8]>) -> R,
+) -> R {
+    let pat: &OsStr = split_every.pat.as_ref();
+    let pat: &[u8] = pat.to_str().map_or(&[], str::as_bytes);
+    let mut view: SplitEvery<&[u8], &[u8]> =
+        split_every.with_parts(split_every.input.as_ref().as_encoded_bytes(), pat);
+    let out: R = f(&mut view);
+    let state: SplitEvery<(), ()> = view.with_parts((), ());
+    split_every.copy_state(&state);
+    out
+}
+
+/// Runs `f` on a view of the deque as a single slice, then keeps the state `f` left it in.
+fn split_every_deque_helper<T, Pattern: ArrPattern<T>, R>(
+    split_every: &mut SplitEvery<VecDeque<T>, Pattern>,
+    f: impl FnOnce(&mut SplitEvery<&[T], BorrowedMut<'_, Pattern>>) -> R,
+) -> R {
+    let state: SplitEvery<(), ()> = split_every.with_parts((), ());
+    let mut view: SplitEvery<&[T], BorrowedMut<'_, Pattern>> = state.with_parts(
+        &*split_every.input.make_contiguous(),
+        BorrowedMut(&mut split_every.pat),
+    );
+    let out: R = f(&mut view);
+    let state: SplitEvery<(), ()> = view.with_parts((), ());
+    split_every.copy_state(&state);
+    out
+}
+
+/// Searches with a mutably borrowed [`ArrPattern`], so that iteration state can be copied
+/// without the pattern.
+struct BorrowedMut<'a, Pattern>(&'a mut Pattern);
+
+impl<T, Pattern: ArrPattern<T>> ArrPattern<T> for BorrowedMut<'_, Pattern> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        self.0.find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        self.0.rfind_in(haystack)
+    }
+}
+
+fn split_every_next_into_arr_helper<T: Clone, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+    buf: &mut Vec<T>,
+) -> bool {
+    buf.clear();
+    let Some(range) = split_every_next_arr_helper(split_every) else {
+        return false;
+    };
+    buf.extend_from_slice(unsafe { split_every.input.as_ref().get_unchecked(range) });
+    true
+}
+
+/// Returns the index range of the next chunk within `input`.
+///
+/// The range is within the checked [`SplitEvery::unsplit_range`], so callers slice `input`
+/// with it unchecked.
+fn split_every_next_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    split_every_next_arr_counted_helper(split_every).map(|(range, _)| range)
+}
+
+/// Like `split_every_next_arr_helper`, but also returns how many patterns were found.
+///
+/// Patterns consumed by skipped empty chunks are added to those of the next chunk.
+fn split_every_next_arr_counted_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<(Range<usize>, usize)> {
+    split_every_skip_empty_helper(split_every, |split_every| {
+        split_every_coalesce_helper(split_every, split_every_next_arr_chunk_helper)
+    })
+}
+
+fn split_every_next_arr_chunk_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T>>(
+    split_every: &mut SplitEvery<Input, Pattern>,
+) -> Option<(Range<usize>, usize)> {
+    if split_every.finished || split_every.max_splits == Some(0) {
+        split_every.finished = true;
+        return None;
+    }
+    let is_whole: bool = !split_every.take_chunk();
+    let input: &[T] = split_every.input.as_ref();
+    let Range { start, end } = split_every.unsplit_range(input.len());
+    if is_whole {
+        return split_every_end_arr_helper(split_every, start..end).map(|range| (range, 0));
+    }
+    let iter_haystack: &[T] = &input[start..end];
+    let mut split: Range<usize> = 0..0;
+    let mut from: usize = 0;
+    for found_count in 0..split_every.n {
+        let haystack: &[T] = unsafe { iter_haystack.get_unchecked(from..) };
+        match split_every_find_checked_arr_helper(&mut split_every.pat, haystack) {
+            Some(found) => {
+                split = unsafe { from.unchecked_add(found.start)..from.unchecked_add(found.end) };
+                from = if split_every.overlapping {
+                    unsafe { split.start.unchecked_add(1) }
+                } else {
+                    split.end
+                };
+            }
+            None => {
+                return split_every_end_arr_helper(split_every, start..end)
+                    .map(|range| (range, found_count))
+            }
+        }
+    }
+    split_every.ind = unsafe { start.unchecked_add(split.end) };
+    if split_every.keeps_pattern() {
+        return Some((start..split_every.ind, split_every.n));
+    }
+    Some((
+        start..unsafe { start.unchecked_add(split.start) },
+        split_every.n,
+    ))
+}
+
+/// Returns the index range `split_every_next_arr_helper` would return, without advancing.
+fn split_every_peek_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T> + Clone>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> Option<Range<usize>> {
+    split_every_next_arr_helper(
+        &mut split_every.with_parts(split_every.input.as_ref(), split_every.pat.clone()),
+    )
+}
+
+/// Counts the ranges `split_every_next_arr_helper` would still return, without advancing.
+fn split_every_chunks_remaining_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T> + Clone>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> usize {
+    let mut view: SplitEvery<&[T], Pattern> =
+        split_every.with_parts(split_every.input.as_ref(), split_every.pat.clone());
+    core::iter::from_fn(|| split_every_next_arr_helper(&mut view)).count()
+}
+
+/// Counts the occurrences of the pattern within the remaining input.
+fn split_every_occurrences_arr_helper<T, Input: AsRef<[T]>, Pattern: ArrPattern<T> + Clone>(
+    split_every: &SplitEvery<Input, Pattern>,
+) -> usize {
+    let input: &[T] = split_every.input.as_ref();
+    let haystack: &[T] = &input[split_every.remaining_range(input.len())];
+    let mut pat: Pattern = split_every.pat.clone();
+    let mut count: usize = 0;
+    let mut from: usize = 0;
+    while let Some(found) =
+        split_every_find_checked_arr_helper(&mut pat, unsafe { haystack.get_unchecked(from..) })
+    {
+        count = unsafe { count.unchecked_add(1) };
+        from = if split_every.overlapping {
+            unsafe { from.unchecked_add(found.start).unchecked_add(1) }
+        } else {
+            unsafe { from.unchecked_add(found.end) }
+        };
+    }
+    count
+}
+
+/// Returns the index range of the first occurrence of `pat` in `haystack`.
+fn split_every_find_arr_helper<T: PartialEq>(haystack: &[T], pat: &[T]) -> Option<Range<usize>> {
+    if pat.is_empty() || pat.len() > haystack.len() {
+        return None;
+    }
+    let ind: usize = match pat {
+        [needle] if split_every_is_byte_helper::<T>() => {
+            // SAFETY: `T` is `u8`, so both casts are between identical types.
+            let haystack: &[u8] = unsafe { &*(haystack as *const [T] as *const [u8]) };
+            let needle: u8 = unsafe { *(needle as *const T as *const u8) };
+            split_every_find_byte_helper(haystack, needle)?
+        }
+        [needle] => haystack.iter().position(|val| val == needle)?,
+        _ => haystack.windows(pat.len()).position(|val| val == pat)?,
+    };
+    Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+}
+
+/// Searches for the last occurrence of `pat` from the end of `haystack`.
+///
+/// Like `split_every_rfind_str_helper`, patterns whose prefix is also their suffix are
+/// searched from the front instead.
+fn split_every_rfind_arr_helper<T: PartialEq>(haystack: &[T], pat: &[T]) -> Option<Range<usize>> {
+    if pat.is_empty() || pat.len() > haystack.len() {
+        return None;
+    }
+    let ind: usize = match pat {
+        [needle] if split_every_is_byte_helper::<T>() => {
+            // SAFETY: `T` is `u8`, so both casts are between identical types.
+            let haystack: &[u8] = unsafe { &*(haystack as *const [T] as *const [u8]) };
+            let needle: u8 = unsafe { *(needle as *const T as *const u8) };
+            split_every_rfind_byte_helper(haystack, needle)?
+        }
+        [needle] => haystack.iter().rposition(|val| val == needle)?,
+        _ if (1..pat.len()).any(|len| pat[..len] == pat[pat.len() - len..]) => {
+            let mut last: Option<usize> = None;
+            let mut from: usize = 0;
+            while let Some(found) = split_every_find_arr_helper(&haystack[from..], pat) {
+                last = Some(from + found.start);
+                from += found.end;
+            }
+            last?
+        }
+        _ => haystack.windows(pat.len()).rposition(|val| val == pat)?,
+    };
+    Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+}
+
+/// Returns whether `T` is `u8`, letting single-element slice patterns reach
+/// `split_every_find_byte_helper` without a separate `ArrPattern<u8>` impl.
+///
+/// `TypeId::of` needs `T: 'static`, so the lookup goes through a trait object whose
+/// lifetime is erased; `u8` has no lifetimes to confuse the comparison.
+fn split_every_is_byte_helper<T>() -> bool {
+    trait NonStaticAny {
+        fn get_type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+    impl<T> NonStaticAny for PhantomData<T> {
+        fn get_type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+    let phantom: PhantomData<T> = PhantomData;
+    let phantom: &dyn NonStaticAny = &phantom;
+    // SAFETY: only the `TypeId` is read, which does not depend on the erased lifetime.
+    let phantom: &(dyn NonStaticAny + 'static) = unsafe { core::mem::transmute(phantom) };
+    phantom.get_type_id() == TypeId::of::<u8>()
+}
+
+fn split_every_find_byte_helper(haystack: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    return memchr::memchr(needle, haystack);
+    #[cfg(not(feature = "memchr"))]
+    return haystack.iter().position(|val| *val == needle);
+}
+
+fn split_every_rfind_byte_helper(haystack: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    return memchr::memrchr(needle, haystack);
+    #[cfg(not(feature = "memchr"))]
+    return haystack.iter().rposition(|val| *val == needle);
+}
+
+/// A pattern that can be searched for within a string.
+///
+/// Matches are never empty, so an empty pattern never matches.
+pub trait StrPattern {
+    /// Returns the byte range of the leftmost match within `haystack`.
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Returns the byte range of the last match `find_in` finds when searching `haystack`
+    /// from the front, skipping past each match.
+    ///
+    /// The default repeats `find_in` over the whole of `haystack`. Patterns that can search
+    /// from the end, such as `char`, override it so that `next_back` only scans the chunks
+    /// it yields.
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let mut last: Option<Range<usize>> = None;
+        let mut from: usize = 0;
+        while let Some(found) =
+            split_every_find_checked_str_helper(self, unsafe { haystack.get_unchecked(from..) })
+        {
+            let split: Range<usize> =
+                unsafe { from.unchecked_add(found.start)..from.unchecked_add(found.end) };
+            from = split.end;
+            last = Some(split);
+        }
+        last
+    }
+}
+
+impl StrPattern for &str {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_find_str_helper(haystack, self)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_rfind_str_helper(haystack, self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl StrPattern for String {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_find_str_helper(haystack, self)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_rfind_str_helper(haystack, self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl StrPattern for alloc::string::Drain<'_> {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_find_str_helper(haystack, self.as_str())
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_rfind_str_helper(haystack, self.as_str())
+    }
+}
+
+/// Matches the leftmost non-empty match of the regex.
+///
+/// The regex only sees the part of the input that has not been split off yet, so
+/// anchors and word boundaries are evaluated relative to that remainder.
+#[cfg(feature = "regex")]
+impl StrPattern for &regex::Regex {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        self.find_iter(haystack)
+            .find(|found| !found.is_empty())
+            .map(|found| found.range())
+    }
+}
+
+impl StrPattern for char {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let ind: usize = haystack.find(*self)?;
+        Some(ind..unsafe { ind.unchecked_add(self.len_utf8()) })
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let ind: usize = haystack.rfind(*self)?;
+        Some(ind..unsafe { ind.unchecked_add(self.len_utf8()) })
+    }
+}
+
+/// Searches for `pat`, checking that the match is safe to slice `haystack` with.
+///
+/// Patterns can be implemented outside this crate, so their matches are not trusted.
+fn split_every_find_checked_str_helper<Pattern: StrPattern + ?Sized>(
+    pat: &Pattern,
+    haystack: &str,
+) -> Option<Range<usize>> {
+    let found: Range<usize> = pat.find_in(haystack)?;
+    assert!(
+        found.start < found.end
+            && haystack.is_char_boundary(found.start)
+            && haystack.is_char_boundary(found.end),
+        "pattern returned an invalid match {found:?}"
+    );
+    Some(found)
+}
+
+/// Searches for `pat`, checking that the match is safe to slice `haystack` with.
+///
+/// Patterns can be implemented outside this crate, so their matches are not trusted.
+fn split_every_find_checked_arr_helper<T, Pattern: ArrPattern<T> + ?Sized>(
+    pat: &mut Pattern,
+    haystack: &[T],
+) -> Option<Range<usize>> {
+    let found: Range<usize> = pat.find_in(haystack)?;
+    assert!(
+        found.start < found.end && found.end <= haystack.len(),
+        "pattern returned an invalid match {found:?}"
+    );
+    Some(found)
+}
+
+/// Like `split_every_find_checked_str_helper`, but returns the last match.
+fn split_every_rfind_checked_str_helper<Pattern: StrPattern + ?Sized>(
+    pat: &Pattern,
+    haystack: &str,
+) -> Option<Range<usize>> {
+    let found: Range<usize> = pat.rfind_in(haystack)?;
+    assert!(
+        found.start < found.end
+            && haystack.is_char_boundary(found.start)
+            && haystack.is_char_boundary(found.end),
+        "pattern returned an invalid match {found:?}"
+    );
+    Some(found)
+}
+
+/// Like `split_every_find_checked_arr_helper`, but returns the last match.
+fn split_every_rfind_checked_arr_helper<T, Pattern: ArrPattern<T> + ?Sized>(
+    pat: &mut Pattern,
+    haystack: &[T],
+) -> Option<Range<usize>> {
+    let found: Range<usize> = pat.rfind_in(haystack)?;
+    assert!(
+        found.start < found.end && found.end <= haystack.len(),
+        "pattern returned an invalid match {found:?}"
+    );
+    Some(found)
+}
+
+/// Searches for the last occurrence of `pat` from the end of `haystack`.
+///
+/// Occurrences of a pattern whose prefix is also its suffix, like `"aba"`, can overlap, and
+/// the last one found from the end may not be one found from the front, so those are
+/// searched from the front instead.
+fn split_every_rfind_str_helper(haystack: &str, pat: &str) -> Option<Range<usize>> {
+    if pat.is_empty() {
+        return None;
+    }
+    let bytes: &[u8] = pat.as_bytes();
+    let overlaps: bool = (1..bytes.len()).any(|len| bytes[..len] == bytes[bytes.len() - len..]);
+    let ind: usize = if overlaps {
+        haystack.match_indices(pat).last()?.0
+    } else {
+        haystack.rfind(pat)?
+    };
+    Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+}
+
+fn split_every_find_str_helper(haystack: &str, pat: &str) -> Option<Range<usize>> {
+    if pat.is_empty() {
+        return None;
+    }
+    let ind: usize = haystack.find(pat)?;
+    Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+}
+
+/// A pattern that can be searched for within a slice.
+///
+/// Matches are never empty, so an empty pattern never matches.
+///
+/// Unlike [`StrPattern`], searching takes `&mut self` so that predicates may keep state.
+pub trait ArrPattern<T> {
+    /// Returns the index range of the leftmost match within `haystack`.
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>>;
+
+    /// Returns the index range of the last match `find_in` finds when searching `haystack`
+    /// from the front, skipping past each match.
+    ///
+    /// The default repeats `find_in` over the whole of `haystack`. Patterns that can search
+    /// from the end, such as `u8`, override it so that `rsplit_every_n_times` only scans the
+    /// chunks it yields.
+    fn rfind_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        let mut last: Option<Range<usize>> = None;
+        let mut from: usize = 0;
+        while let Some(found) =
+            split_every_find_checked_arr_helper(self, unsafe { haystack.get_unchecked(from..) })
+        {
+            let split: Range<usize> =
+                unsafe { from.unchecked_add(found.start)..from.unchecked_add(found.end) };
+            from = split.end;
+            last = Some(split);
+        }
+        last
+    }
+}
+
+impl<T: PartialEq> ArrPattern<T> for Vec<T> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_find_arr_helper(haystack, self)
+    }
+
+    fn rfind_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_rfind_arr_helper(haystack, self)
+    }
+}
+
+impl<T: PartialEq, const N: usize> ArrPattern<T> for [T; N] {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_find_arr_helper(haystack, self)
+    }
+
+    fn rfind_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_rfind_arr_helper(haystack, self)
+    }
+}
+
+/// Any borrowed slice-like pattern, such as `&[T]`, `&[T; N]`, `&Vec<T>`, or a reference
+/// to a small-vector type implementing `AsRef<[T]>`.
+impl<T: PartialEq, Pattern: AsRef<[T]> + ?Sized> ArrPattern<T> for &Pattern {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_find_arr_helper(haystack, (*self).as_ref())
+    }
+
+    fn rfind_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_rfind_arr_helper(haystack, (*self).as_ref())
+    }
+}
+
+/// A single byte, searched for with `memchr` when the `memchr` feature is enabled.
+impl ArrPattern<u8> for u8 {
+    fn find_in(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        let ind: usize = split_every_find_byte_helper(haystack, *self)?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+
+    fn rfind_in(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        let ind: usize = split_every_rfind_byte_helper(haystack, *self)?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+}
+
+/// A single UTF-16 code unit, such as the `0` separating entries of a wide-string list.
+impl ArrPattern<u16> for u16 {
+    fn find_in(&mut self, haystack: &[u16]) -> Option<Range<usize>> {
+        let ind: usize = haystack.iter().position(|val| val == self)?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+
+    fn rfind_in(&mut self, haystack: &[u16]) -> Option<Range<usize>> {
+        let ind: usize = haystack.iter().rposition(|val| val == self)?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+}
+
+/// Matches any of several patterns, preferring the leftmost-longest match.
+#[derive(Clone, Copy, Debug)]
+pub struct AnyOf<'a, Pattern>(pub &'a [Pattern]);
+
+impl<Pattern: StrPattern> StrPattern for AnyOf<'_, Pattern> {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        split_every_find_any_helper(self.0.iter().map(|pat| pat.find_in(haystack)))
+    }
+}
+
+impl<T: PartialEq> ArrPattern<T> for AnyOf<'_, Vec<T>> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_find_any_helper(
+            self.0
+                .iter()
+                .map(|pat| split_every_find_arr_helper(haystack, pat)),
+        )
+    }
+}
+
+impl<T: PartialEq> ArrPattern<T> for AnyOf<'_, &[T]> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        split_every_find_any_helper(
+            self.0
+                .iter()
+                .map(|pat| split_every_find_arr_helper(haystack, pat)),
+        )
+    }
+}
+
+/// Matches every element for which the predicate returns `true`.
+#[derive(Clone, Copy)]
+pub struct By<F>(pub F);
+
+impl<F> Debug for By<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("By").field(&format_args!("<fn>")).finish()
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> ArrPattern<T> for By<F> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        let ind: usize = haystack.iter().position(&mut self.0)?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+}
+
+/// Matches the wrapped pattern, comparing elements with a custom equality instead of `==`.
+#[derive(Clone, Copy)]
+pub struct ByEq<Pattern, F>(pub Pattern, pub F);
+
+impl<Pattern: Debug, F> Debug for ByEq<Pattern, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ByEq")
+            .field(&self.0)
+            .field(&format_args!("<fn>"))
+            .finish()
+    }
+}
+
+impl<T, Pattern: AsRef<[T]>, F: FnMut(&T, &T) -> bool> ArrPattern<T> for ByEq<Pattern, F> {
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        let pat: &[T] = self.0.as_ref();
+        if pat.is_empty() || pat.len() > haystack.len() {
+            return None;
+        }
+        let ind: usize = haystack
+            .windows(pat.len())
+            .position(|val| val.iter().zip(pat).all(|(val, pat)| (self.1)(val, pat)))?;
+        Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+    }
+}
+
+/// Matches the wrapped pattern ignoring ASCII case.
+#[derive(Clone, Copy, Debug)]
+pub struct IgnoreAsciiCase<Pattern>(pub Pattern);
+
+impl<Pattern: AsRef<str>> StrPattern for IgnoreAsciiCase<Pattern> {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let pat: &[u8] = self.0.as_ref().as_bytes();
+        if pat.is_empty() || pat.len() > haystack.len() {
+            return None;
+        }
+        // Only ASCII bytes compare case-insensitively, so matches start on a char boundary.
+        let ind: usize = haystack
+            .as_bytes()
+            .windows(pat.len())
+            .position(|val| val.eq_ignore_ascii_case(pat))?;
+        Some(ind..unsafe { ind.unchecked_add(pat.len()) })
+    }
+}
+
+/// Matches any single byte in a set, checked against a 256-bit table.
+///
+/// In strings only ASCII members match, so a match never splits a char.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// Creates a set of the bytes in `set`.
+    pub fn new(set: &[u8]) -> Self {
+        let mut table: [u64; 4] = [0; 4];
+        for val in set {
+            table[usize::from(val >> 6)] |= 1 << (val & 63);
+        }
+        ByteSet(table)
+    }
+
+    /// Returns whether `byte` is in the set.
+    pub fn contains(&self, byte: u8) -> bool {
+        self.0[usize::from(byte >> 6)] & (1 << (byte & 63)) != 0
+    }
+}
+
+impl StrPattern for ByteSet {
+    fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let ind: usize = haystack
+            .bytes()
+            .position(|val| val.is_ascii() && self.contains(val))?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+        let ind: usize = haystack
+            .bytes()
+            .rposition(|val| val.is_ascii() && self.contains(val))?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+}
+
+impl ArrPattern<u8> for ByteSet {
+    fn find_in(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        let ind: usize = haystack.iter().position(|val| self.contains(*val))?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+
+    fn rfind_in(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        let ind: usize = haystack.iter().rposition(|val| self.contains(*val))?;
+        Some(ind..unsafe { ind.unchecked_add(1) })
+    }
+}
+
+fn split_every_find_any_helper(
+    found: impl Iterator<Item = Option<Range<usize>>>,
+) -> Option<Range<usize>> {
+    found
+        .flatten()
+        .min_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)))
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test() {
+    let mut splitter: SplitEvery<&str, &str> = "oh oh oh oh oh".split_every_n_times(" ", 2);
+    assert_eq!(splitter.next().unwrap(), "oh oh");
+    assert_eq!(splitter.next().unwrap(), "oh oh");
+    assert_eq!(splitter.next().unwrap(), "oh");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a a a a".split_every_n_times("b", 2);
+    assert_eq!(splitter.next().unwrap(), "a a a a");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> = [
+        ["This", "is", "you"],
+        ["This", "is", "me"],
+        ["This", "is", "someone"],
+        ["This", "is", "them"],
+    ]
+    .iter()
+    .flatten()
+    .copied()
+    .split_every_n_times("is", 2);
+    assert_eq!(splitter.next().unwrap(), vec!["This", "is", "you", "This"]);
+    assert_eq!(
+        splitter.next().unwrap(),
+        vec!["me", "This", "is", "someone", "This"]
+    );
+    assert_eq!(splitter.next().unwrap(), vec!["them"]);
+    assert_eq!(splitter.next(), None);
+
+    let mut iter = [
+        ["This", "is", "you"],
+        ["This", "is", "me"],
+        ["This", "is", "someone"],
+        ["This", "is", "them"],
+    ]
+    .iter()
+    .flatten()
+    .copied();
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> =
+        SplitEvery::<Box<dyn FnMut() -> Option<&'static str>>, &str>::n_times_from_fn(
+            Box::new(move || iter.next()),
+            "is",
+            2,
+        );
     assert_eq!(splitter.next().unwrap(), vec!["This", "is", "you", "This"]);
     assert_eq!(
-        splitter.next().unwrap(),
-        vec!["me", "This", "is", "someone", "This"]
+        splitter.next().unwrap(),
+        vec!["me", "This", "is", "someone", "This"]
+    );
+    assert_eq!(splitter.next().unwrap(), vec!["them"]);
+    assert_eq!(splitter.next(), None);
+
+    #[allow(clippy::type_complexity)]
+    let mut splitter: SplitEvery<&[(u8, u8)], &[(u8, u8)]> = [
+        (0, 0),
+        (0, 1),
+        (0, 0), // Split
+        (0, 0),
+        (0, 0), // Split
+        (0, 1),
+        (0, 0),
+        (0, 0), // Split
+        (0, 1),
+    ]
+    .as_slice()
+    .split_every_n_times(&[(0, 0)], 2);
+    assert_eq!(splitter.next().unwrap(), vec![(0, 0), (0, 1)]);
+    assert_eq!(splitter.next().unwrap(), vec![(0, 0)]);
+    assert_eq!(splitter.next().unwrap(), vec![(0, 1), (0, 0)]);
+    assert_eq!(splitter.next().unwrap(), vec![(0, 1)]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> =
+        "Oh hi there I don't really know what to say".split_every_n_times(" ", 3);
+    assert_eq!(splitter.next().unwrap(), "Oh hi there");
+    assert_eq!(splitter.next().unwrap(), "I don't really");
+    assert_eq!(splitter.next().unwrap(), "know what to");
+    assert_eq!(splitter.next().unwrap(), "say");
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_next_back() {
+    let mut splitter: SplitEvery<&str, &str> = "oh oh oh oh oh".split_every_n_times(" ", 2);
+    assert_eq!(splitter.next_back().unwrap(), "oh");
+    assert_eq!(splitter.next_back().unwrap(), "oh oh");
+    assert_eq!(splitter.next_back().unwrap(), "oh oh");
+    assert_eq!(splitter.next_back(), None);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 3);
+    assert_eq!(splitter.next().unwrap(), "a b c");
+    assert_eq!(splitter.next().unwrap(), "d e");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 3);
+    assert_eq!(splitter.next_back().unwrap(), "d e");
+    assert_eq!(splitter.next_back().unwrap(), "a b c");
+    assert_eq!(splitter.next_back(), None);
+
+    // Ending exactly on a pattern boundary yields no trailing empty chunk from either end.
+    let mut splitter: SplitEvery<String, String> = "a b c d "
+        .to_string()
+        .split_every_n_times(" ".to_string(), 2);
+    assert_eq!(splitter.next_back().unwrap(), "c d");
+    assert_eq!(splitter.next_back().unwrap(), "a b");
+    assert_eq!(splitter.next_back(), None);
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c,".split_every_n_times(",", 1);
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next_back().unwrap(), "c");
+    assert_eq!(splitter.next_back().unwrap(), "b");
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEvery<&str, &str> =
+        "Oh hi there I don't really know what to say".split_every_n_times(" ", 3);
+    assert_eq!(splitter.next().unwrap(), "Oh hi there");
+    assert_eq!(splitter.next_back().unwrap(), "say");
+    assert_eq!(splitter.next_back().unwrap(), "know what to");
+    assert_eq!(splitter.next().unwrap(), "I don't really");
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a,,b,".split_every_n_times(",", 1);
+    assert_eq!(splitter.next_back().unwrap(), "b");
+    assert_eq!(splitter.next_back().unwrap(), "");
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next(), None);
+
+    assert_eq!(
+        "oh oh oh oh oh"
+            .split_every_n_times(" ", 2)
+            .rev()
+            .collect::<Vec<String>>(),
+        vec!["oh", "oh oh", "oh oh"]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ref() {
+    let input: &str = "Oh hi there I don't really know what to say";
+    let mut splitter: SplitEveryRef<&str, &str> = input.split_every_n_times_ref(" ", 3);
+    let chunk: &str = splitter.next().unwrap();
+    assert_eq!(chunk, "Oh hi there");
+    assert_eq!(chunk.as_ptr(), input.as_ptr());
+    assert_eq!(splitter.next().unwrap(), "I don't really");
+    assert_eq!(splitter.next().unwrap(), "know what to");
+    assert_eq!(splitter.next().unwrap(), "say");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, &str> = "a a a a".split_every_n_times_ref("b", 2);
+    assert_eq!(splitter.next().unwrap(), "a a a a");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, &str> = "a b c".split_every_n_times_ref(" ", 0);
+    assert_eq!(splitter.next().unwrap(), "a b c");
+    assert_eq!(splitter.next(), None);
+
+    let input: String = "oh oh oh oh oh".to_string();
+    let chunks: Vec<&str> = input
+        .as_str()
+        .split_every_n_times_ref(" ", 2)
+        .rev()
+        .collect();
+    assert_eq!(chunks, vec!["oh", "oh oh", "oh oh"]);
+    assert_eq!(
+        chunks,
+        input
+            .as_str()
+            .split_every_n_times(" ", 2)
+            .rev()
+            .collect::<Vec<String>>()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_empty_pattern() {
+    for n in [0, 1, 2, 3] {
+        let mut splitter: SplitEvery<&str, &str> = "abc".split_every_n_times("", n);
+        assert_eq!(splitter.next().unwrap(), "abc");
+        assert_eq!(splitter.next(), None);
+
+        let mut splitter: SplitEvery<&str, &str> = "abc".split_every_n_times("", n);
+        assert_eq!(splitter.next_back().unwrap(), "abc");
+        assert_eq!(splitter.next_back(), None);
+
+        let mut splitter: SplitEvery<&[u8], &[u8]> =
+            [1, 2, 3].as_slice().split_every_n_times(&[], n);
+        assert_eq!(splitter.next().unwrap(), vec![1, 2, 3]);
+        assert_eq!(splitter.next(), None);
+
+        let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+            vec![1, 2, 3].split_every_n_times(vec![], n);
+        assert_eq!(splitter.peek_chunk().unwrap(), [1, 2, 3]);
+        assert_eq!(splitter.occurrences_left(), 0);
+        assert_eq!(splitter.next().unwrap(), vec![1, 2, 3]);
+        assert_eq!(splitter.next(), None);
+
+        // An empty alternative never matches either, while the others still do.
+        let pats: [Vec<u8>; 2] = [vec![], vec![2]];
+        let splitter: SplitEvery<Vec<u8>, AnyOf<Vec<u8>>> =
+            vec![1, 2, 3].split_every_n_times_any(&pats, n);
+        let expected: Vec<Vec<u8>> = match n {
+            1 => vec![vec![1], vec![3]],
+            _ => vec![vec![1, 2, 3]],
+        };
+        assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), expected);
+    }
+
+    // The closure path compares whole elements, so `""` is matched like any other element.
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> =
+        ["a", "", "b", "", "c"]
+            .into_iter()
+            .split_every_n_times("", 0);
+    assert_eq!(splitter.next().unwrap(), vec!["a", "", "b", "", "c"]);
+    assert_eq!(splitter.next(), None);
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> =
+        ["a", "", "b", "", "c"]
+            .into_iter()
+            .split_every_n_times("", 1);
+    assert_eq!(splitter.next().unwrap(), vec!["a"]);
+    assert_eq!(splitter.next().unwrap(), vec!["b"]);
+    assert_eq!(splitter.next().unwrap(), vec!["c"]);
+    assert_eq!(splitter.next(), None);
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<&'static str>>, &str> =
+        ["a", "", "b", "", "c"]
+            .into_iter()
+            .split_every_n_times("", 3);
+    assert_eq!(splitter.next().unwrap(), vec!["a", "", "b", "", "c"]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_oversized_pattern() {
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 2].split_every_n_times(vec![1, 2, 3, 4], 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 2]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> =
+        [1, 2].as_slice().split_every_n_times(&[1, 2, 3, 4], 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 2]);
+    assert_eq!(splitter.next(), None);
+
+    // The pattern only becomes oversized once the remaining input shrinks.
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 2, 3, 4, 1, 2].split_every_n_times(vec![1, 2, 3], 1);
+    assert_eq!(splitter.next().unwrap(), Vec::<u8>::new());
+    assert_eq!(splitter.next().unwrap(), vec![4, 1, 2]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> = [].as_slice().split_every_n_times(&[1, 2], 1);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> = vec![1, 2].split_every_n_times(vec![], 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 2]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> = [1, 0, 2].as_slice().split_every_n_times(&[0], 3);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_fused() {
+    fn assert_fused<T: FusedIterator>(mut iter: T) {
+        while iter.next().is_some() {}
+        for _ in 0..5 {
+            assert!(iter.next().is_none());
+        }
+    }
+
+    assert_fused("oh oh oh".split_every_n_times(" ", 2));
+    assert_fused(
+        "oh oh oh"
+            .to_string()
+            .split_every_n_times(" ".to_string(), 2),
+    );
+    let mut input: String = "oh oh oh".to_string();
+    let mut pat: String = " ".to_string();
+    assert_fused(SplitEveryImpl::split_every_n_times(
+        input.drain(..),
+        pat.drain(..),
+        2,
+    ));
+    assert_fused("oh oh oh".split_every_n_times_ref(" ", 2));
+    assert_fused(vec![1, 0, 1, 0, 1].split_every_n_times(vec![0], 2));
+    assert_fused([1, 0, 1, 0, 1].split_every_n_times(&[0], 2));
+    assert_fused([1, 0, 1, 0, 1].into_iter().split_every_n_times(0, 2));
+
+    // Even a source that resumes after returning `None` is not polled again.
+    let mut count: usize = 0;
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<usize>>, usize> =
+        SplitEvery::n_times_from_fn(
+            Box::new(move || {
+                count += 1;
+                (!count.is_multiple_of(3)).then_some(count)
+            }),
+            0,
+            1,
+        );
+    assert_eq!(splitter.next().unwrap(), vec![1, 2]);
+    for _ in 0..5 {
+        assert_eq!(splitter.next(), None);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_size_hint() {
+    fn assert_size_hint<T: Iterator>(build: impl Fn() -> T) {
+        let total: usize = build().count();
+        let mut iter: T = build();
+        for remaining in (0..=total).rev() {
+            let (lower, upper): (usize, Option<usize>) = iter.size_hint();
+            assert!(lower <= remaining);
+            assert!(upper.unwrap() >= remaining);
+            iter.next();
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    assert_size_hint(|| "oh oh oh oh oh".split_every_n_times(" ", 2));
+    assert_size_hint(|| "a,,b,".split_every_n_times(",", 1));
+    assert_size_hint(|| "".split_every_n_times(",", 1));
+    assert_size_hint(|| "abc".split_every_n_times("", 2));
+    assert_size_hint(|| {
+        "oh oh oh"
+            .to_string()
+            .split_every_n_times(" ".to_string(), 0)
+    });
+    assert_size_hint(|| "oh  oh  oh".split_every_n_times_ref("  ", 1));
+    assert_size_hint(|| vec![1, 0, 1, 0, 1].split_every_n_times(vec![0], 1));
+    assert_size_hint(|| [0, 0, 0].split_every_n_times(&[0][..], 2));
+
+    assert_eq!(
+        "oh oh oh oh oh".split_every_n_times(" ", 2).size_hint(),
+        (1, Some(8))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_remainder() {
+    let mut splitter: SplitEvery<&str, &str> =
+        "Oh hi there I don't really know what to say".split_every_n_times(" ", 3);
+    assert_eq!(
+        splitter.remainder(),
+        "Oh hi there I don't really know what to say"
+    );
+    assert_eq!(splitter.next().unwrap(), "Oh hi there");
+    assert_eq!(splitter.remainder(), "I don't really know what to say");
+    assert_eq!(splitter.next_back().unwrap(), "say");
+    assert_eq!(splitter.remainder(), "I don't really know what to");
+    let (input, pat, n): (&str, &str, usize) = splitter.into_inner();
+    assert_eq!(input, "Oh hi there I don't really know what to say");
+    assert_eq!((pat, n), (" ", 3));
+
+    // Resume splitting the remainder with a different `n`.
+    let mut splitter: SplitEvery<String, String> = "a b c d e f"
+        .to_string()
+        .split_every_n_times(" ".to_string(), 1);
+    assert_eq!(splitter.next().unwrap(), "a");
+    let mut splitter: SplitEvery<&str, &str> = splitter.remainder().split_every_n_times(" ", 2);
+    assert_eq!(splitter.next().unwrap(), "b c");
+    assert_eq!(splitter.next().unwrap(), "d e");
+    assert_eq!(splitter.next().unwrap(), "f");
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.remainder(), "");
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 0, 2, 0, 3].split_every_n_times(vec![0], 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.remainder(), &[2, 0, 3]);
+    assert_eq!(splitter.into_inner(), (vec![1, 0, 2, 0, 3], vec![0], 1));
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> = [1, 0, 2].as_slice().split_every_n_times(&[0], 1);
+    assert_eq!(splitter.remainder(), &[1, 0, 2]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.remainder(), &[] as &[u8]);
+
+    let mut splitter: SplitEveryRef<&str, &str> = "a,b".split_every_n_times_ref(",", 1);
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.remainder(), "b");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_clone() {
+    let mut splitter: SplitEvery<&str, &str> =
+        "Oh hi there I don't really know what to say".split_every_n_times(" ", 3);
+    assert_eq!(splitter.next().unwrap(), "Oh hi there");
+    let cloned: SplitEvery<&str, &str> = splitter.clone();
+    assert_eq!(
+        splitter.collect::<Vec<String>>(),
+        cloned.collect::<Vec<String>>()
+    );
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 0, 2, 0, 3].split_every_n_times(vec![0], 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.clone().count(), 2);
+    assert_eq!(splitter.count(), 2);
+
+    // A closure-backed splitter is `Clone` whenever its closure is.
+    let mut iter = [1, 0, 2, 0, 3].into_iter();
+    let mut splitter = SplitEvery::n_times_from_fn(move || iter.next(), 0, 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(
+        splitter.clone().collect::<Vec<Vec<u8>>>(),
+        vec![vec![2], vec![3]]
+    );
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), vec![vec![2], vec![3]]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_debug() {
+    let mut splitter: SplitEvery<&str, &str> = "a b c".split_every_n_times(" ", 2);
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"SplitEvery { input: "a b c", pat: " ", n: 2, ind: 0, .. }"#
+    );
+    splitter.next();
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"SplitEvery { input: "a b c", pat: " ", n: 2, ind: 4, .. }"#
+    );
+
+    let splitter: SplitEvery<Vec<u8>, Vec<u8>> = vec![1, 0, 2].split_every_n_times(vec![0], 1);
+    assert_eq!(
+        format!("{splitter:?}"),
+        "SplitEvery { input: [1, 0, 2], pat: [0], n: 1, ind: 0, .. }"
+    );
+
+    let splitter: SplitEveryRef<&str, &str> = "a,b".split_every_n_times_ref(",", 1);
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"SplitEveryRef { input: "a,b", pat: ",", n: 1, ind: 0, .. }"#
+    );
+
+    #[derive(PartialEq)]
+    struct NotDebug;
+    let splitter = SplitEvery::n_times_from_fn(|| None::<NotDebug>, NotDebug, 3);
+    assert_eq!(
+        format!("{splitter:?}"),
+        "SplitEvery { input: <fn>, n: 3, .. }"
+    );
+
+    let splitter: SplitEvery<&str, &str> = "a b".split_every_n_times(" ", 1).trim_chunks(true);
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"SplitEvery { input: "a b", pat: " ", n: 1, ind: 0, trim: true, .. }"#
+    );
+    assert_eq!(
+        format!("{:?}", SplitEverySpec::new(",", 2)),
+        r#"SplitEverySpec { pat: ",", n: 2 }"#
+    );
+    let spec: SplitEverySpec<&str> = SplitEverySpec::builder()
+        .pattern(",")
+        .every(2)
+        .require_pattern(true)
+        .min_chunk_len(3)
+        .max_splits(4)
+        .spec();
+    assert_eq!(
+        format!("{spec:?}"),
+        r#"SplitEverySpec { pat: ",", n: 2, require_pattern: true, max_splits: 4, min_chunk_len: 3 }"#
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_inclusive() {
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c,d".split_every_n_times_inclusive(",", 2);
+    assert_eq!(splitter.next().unwrap(), "a,b,");
+    assert_eq!(splitter.next().unwrap(), "c,d");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c,d,".split_every_n_times_inclusive(",", 2);
+    assert_eq!(splitter.next().unwrap(), "a,b,");
+    assert_eq!(splitter.next().unwrap(), "c,d,");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c,d,".split_every_n_times_inclusive(",", 2);
+    assert_eq!(splitter.next_back().unwrap(), "c,d,");
+    assert_eq!(splitter.next_back().unwrap(), "a,b,");
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEvery<String, String> = "a,b,c"
+        .to_string()
+        .split_every_n_times_inclusive(",".to_string(), 1);
+    assert_eq!(splitter.next_back().unwrap(), "c");
+    assert_eq!(splitter.next().unwrap(), "a,");
+    assert_eq!(splitter.next_back().unwrap(), "b,");
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEveryRef<&str, &str> = ",,a".split_every_n_times_ref_inclusive(",", 1);
+    assert_eq!(splitter.next().unwrap(), ",");
+    assert_eq!(splitter.next().unwrap(), ",");
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c".split_every_n_times_inclusive(",", 0);
+    assert_eq!(splitter.next().unwrap(), "a,b,c");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 0, 2, 0, 3, 0].split_every_n_times_inclusive(vec![0], 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2, 0]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 0]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> = [1, 0, 2, 0, 3]
+        .as_slice()
+        .split_every_n_times_inclusive(&[0], 0);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2, 0, 3]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<u8>>, u8> = [1, 0, 2, 0, 3]
+        .into_iter()
+        .split_every_n_times_inclusive(0, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2, 0]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<u8>>, u8> = [1, 0, 2, 0, 3]
+        .into_iter()
+        .split_every_n_times_inclusive(0, 0);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2, 0, 3]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_any() {
+    let mut splitter: SplitEvery<&str, AnyOf<&str>> =
+        "a, b; c, d; e, f; g".split_every_n_times_any(&[", ", "; "], 3);
+    assert_eq!(splitter.next().unwrap(), "a, b; c");
+    assert_eq!(splitter.next().unwrap(), "d; e, f");
+    assert_eq!(splitter.next().unwrap(), "g");
+    assert_eq!(splitter.next(), None);
+
+    // Overlapping patterns resolve to the leftmost-longest match.
+    let mut splitter: SplitEvery<&str, AnyOf<&str>> =
+        "1ab2abc3ab4".split_every_n_times_any(&["ab", "abc"], 1);
+    assert_eq!(splitter.next().unwrap(), "1");
+    assert_eq!(splitter.next().unwrap(), "2");
+    assert_eq!(splitter.next().unwrap(), "3");
+    assert_eq!(splitter.next().unwrap(), "4");
+    assert_eq!(splitter.next(), None);
+    let mut splitter: SplitEvery<&str, AnyOf<&str>> =
+        "1ab2abc3ab4".split_every_n_times_any(&["abc", "ab"], 2);
+    assert_eq!(splitter.next_back().unwrap(), "3ab4");
+    assert_eq!(splitter.next_back().unwrap(), "1ab2");
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEvery<&str, AnyOf<&str>> = "a b".split_every_n_times_any(&[], 1);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next(), None);
+
+    let pats: [Vec<u8>; 2] = [vec![0], vec![9, 9]];
+    let mut splitter: SplitEvery<Vec<u8>, AnyOf<Vec<u8>>> =
+        vec![1, 0, 2, 9, 9, 3, 0, 4].split_every_n_times_any(&pats, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 0, 4]);
+    assert_eq!(splitter.next(), None);
+
+    let pats: [&[u8]; 2] = [&[1, 2], &[1, 2, 3]];
+    let mut splitter: SplitEvery<&[u8], AnyOf<&[u8]>> = [1, 2, 3, 1, 2, 4]
+        .as_slice()
+        .split_every_n_times_any(&pats, 1);
+    assert_eq!(splitter.next().unwrap(), Vec::<u8>::new());
+    assert_eq!(splitter.next().unwrap(), Vec::<u8>::new());
+    assert_eq!(splitter.next().unwrap(), vec![4]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_by() {
+    let mut splitter =
+        vec![1, 10, 2, 20, 3, 30, 4].split_every_n_times_by(|x: &i32| x % 10 == 0, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 10, 2]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 30, 4]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter = [1, 10, 2, 20, 3][..].split_every_n_times_by(|x: &i32| x % 10 == 0, 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter = ["This", "is", "a", "sentence", "of", "short", "words"]
+        .into_iter()
+        .split_every_n_times_by(|word: &&str| word.len() > 5, 1);
+    assert_eq!(splitter.next().unwrap(), vec!["This", "is", "a"]);
+    assert_eq!(splitter.next().unwrap(), vec!["of", "short", "words"]);
+    assert_eq!(splitter.next(), None);
+
+    // The matched element does not need to be `PartialEq`.
+    #[derive(Clone, Debug)]
+    struct Word(&'static str);
+    let mut splitter = vec![Word("a"), Word(""), Word("b")]
+        .split_every_n_times_by(|word: &Word| word.0.is_empty(), 1);
+    assert_eq!(splitter.next().unwrap().len(), 1);
+    assert_eq!(splitter.next().unwrap().len(), 1);
+    assert!(splitter.next().is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_max_splits() {
+    let mut splitter: SplitEvery<&str, &str> =
+        "a b c d e".split_every_n_times(" ", 1).with_max_splits(2);
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next().unwrap(), "b c d e");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e f g"
+        .split_every_n_times(" ", 2)
+        .with_max_splits(2);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next().unwrap(), "c d e f g");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e f g"
+        .split_every_n_times(" ", 2)
+        .with_max_splits(2);
+    assert_eq!(splitter.next_back().unwrap(), "c d e f g");
+    assert_eq!(splitter.next_back().unwrap(), "a b");
+    assert_eq!(splitter.next_back(), None);
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e f g"
+        .split_every_n_times(" ", 2)
+        .with_max_splits(3);
+    assert_eq!(splitter.next_back().unwrap(), "e f g");
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next().unwrap(), "c d");
+    assert_eq!(splitter.next(), None);
+
+    // The limit is not reached when there are fewer chunks.
+    let mut splitter: SplitEvery<&str, &str> =
+        "a b c".split_every_n_times(" ", 2).with_max_splits(5);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next().unwrap(), "c");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, &str> =
+        "a b c".split_every_n_times(" ", 1).with_max_splits(0);
+    assert_eq!(splitter.size_hint(), (0, Some(0)));
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> = vec![1, 0, 2, 0, 3, 0, 4, 0, 5]
+        .split_every_n_times(vec![0], 2)
+        .with_max_splits(2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 0, 4, 0, 5]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<u8>>, u8> = [1, 0, 2, 0, 3, 0, 4, 0, 5]
+        .into_iter()
+        .split_every_n_times(0, 2)
+        .with_max_splits(2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 0, 4, 0, 5]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, &str> =
+        "a,b,c".split_every_n_times_ref(",", 1).with_max_splits(1);
+    assert_eq!(splitter.next().unwrap(), "a,b,c");
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_char() {
+    let mut splitter: SplitEvery<&str, char> = "a,b,c".split_every_n_times(',', 2);
+    assert_eq!(splitter.next().unwrap(), "a,b");
+    assert_eq!(splitter.next().unwrap(), "c");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<String, char> = "1€2€3€4".to_string().split_every_n_times('€', 2);
+    assert_eq!(splitter.next().unwrap(), "1€2");
+    assert_eq!(splitter.next().unwrap(), "3€4");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, char> = "€€a€".split_every_n_times('€', 1);
+    assert_eq!(splitter.next_back().unwrap(), "a");
+    assert_eq!(splitter.next().unwrap(), "");
+    assert_eq!(splitter.next().unwrap(), "");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, char> = "x€y".split_every_n_times_ref_inclusive('€', 1);
+    assert_eq!(splitter.next().unwrap(), "x€");
+    assert_eq!(splitter.next().unwrap(), "y");
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn test_no_std() {
+    use alloc::vec;
+
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 2);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next_back().unwrap(), "e");
+    assert_eq!(splitter.next().unwrap(), "c d");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, char> = "a,b,c".split_every_n_times_ref(',', 2);
+    assert_eq!(splitter.next().unwrap(), "a,b");
+    assert_eq!(splitter.next().unwrap(), "c");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&[u8], &[u8]> =
+        [1, 0, 1, 0, 1].as_slice().split_every_n_times(&[0][..], 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 1]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter = vec![1, 0, 1, 0, 1].split_every_n_times_by(|x: &u8| *x == 0, 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter = [1, 0, 1, 0, 1].into_iter().split_every_n_times(0, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 1]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_byte() {
+    let mut splitter: SplitEvery<&[u8], u8> =
+        b"a\nb\nc\nd\ne".as_slice().split_every_n_times(b'\n', 2);
+    assert_eq!(splitter.next().unwrap(), b"a\nb");
+    assert_eq!(splitter.next().unwrap(), b"c\nd");
+    assert_eq!(splitter.next().unwrap(), b"e");
+    assert_eq!(splitter.next(), None);
+
+    let input: Vec<u8> = b"\n1\n\n2\n".to_vec();
+    assert_eq!(
+        input
+            .clone()
+            .split_every_n_times(b'\n', 1)
+            .collect::<Vec<Vec<u8>>>(),
+        input
+            .clone()
+            .split_every_n_times(&[b'\n'][..], 1)
+            .collect::<Vec<Vec<u8>>>()
+    );
+    assert_eq!(
+        input
+            .clone()
+            .split_every_n_times(vec![b'\n'], 1)
+            .collect::<Vec<Vec<u8>>>(),
+        input
+            .split_every_n_times([b'\n'], 1)
+            .collect::<Vec<Vec<u8>>>()
+    );
+
+    assert!(split_every_is_byte_helper::<u8>());
+    assert!(!split_every_is_byte_helper::<i8>());
+    assert!(!split_every_is_byte_helper::<&u8>());
+    let signed: [i8; 3] = [1, -1, 2];
+    assert_eq!(
+        signed
+            .as_slice()
+            .split_every_n_times([-1], 1)
+            .collect::<Vec<Vec<i8>>>(),
+        [vec![1], vec![2]]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_iter() {
+    let input = ["a", "b", "a", "c", "a", "d"];
+    let mut splitter: SplitEveryIter<std::iter::Copied<std::slice::Iter<&str>>, &str> =
+        input.iter().copied().split_every_n_times_iter("a", 2);
+    assert_eq!(splitter.next().unwrap(), vec!["a", "b"]);
+    assert_eq!(splitter.next().unwrap(), vec!["c", "a", "d"]);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next(), None);
+
+    assert_eq!(
+        input
+            .iter()
+            .copied()
+            .split_every_n_times_iter("a", 1)
+            .collect::<Vec<Vec<&str>>>(),
+        input
+            .iter()
+            .copied()
+            .split_every_n_times("a", 1)
+            .collect::<Vec<Vec<&str>>>()
+    );
+
+    let splitter = [1, 0, 1, 0, 1]
+        .into_iter()
+        .split_every_n_times_iter(0, 1)
+        .with_max_splits(2);
+    assert!(format!("{splitter:?}").starts_with("SplitEveryIter { input: IntoIter("));
+    assert_eq!(
+        splitter.collect::<Vec<Vec<i32>>>(),
+        vec![vec![1], vec![1, 0, 1]]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let mut splitter: SplitEvery<String, String> = "a b c d e f g"
+        .to_string()
+        .split_every_n_times(" ".to_string(), 2);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.next_back().unwrap(), "g");
+    assert_eq!(splitter.next().unwrap(), "c d");
+    let json: String = serde_json::to_string(&splitter).unwrap();
+    let resumed: SplitEvery<String, String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        resumed.collect::<Vec<String>>(),
+        splitter.collect::<Vec<String>>()
+    );
+
+    let mut splitter: SplitEveryRef<&str, &str> = "a,b,c".split_every_n_times_ref(",", 1);
+    assert_eq!(splitter.next().unwrap(), "a");
+    let json: String = serde_json::to_string(&splitter).unwrap();
+    let resumed: SplitEveryRef<&str, &str> = serde_json::from_str(&json).unwrap();
+    assert_eq!(resumed.collect::<Vec<&str>>(), vec!["b", "c"]);
+
+    // Every state iteration reaches from either end resumes.
+    for (back, max_splits) in [
+        (false, None),
+        (true, None),
+        (false, Some(2)),
+        (true, Some(2)),
+    ] {
+        let mut splitter: SplitEvery<String, String> = "é,a,,b,é,"
+            .to_string()
+            .split_every_n_times(",".to_string(), 2);
+        if let Some(max_splits) = max_splits {
+            splitter = splitter.with_max_splits(max_splits);
+        }
+        loop {
+            let json: String = serde_json::to_string(&splitter).unwrap();
+            let resumed: SplitEvery<String, String> = serde_json::from_str(&json).unwrap();
+            assert!(resumed == splitter);
+            let chunk: Option<String> = if back {
+                splitter.next_back()
+            } else {
+                splitter.next()
+            };
+            if chunk.is_none() {
+                break;
+            }
+        }
+    }
+
+    // States iteration cannot reach would slice out of bounds or inside a char.
+    let mut splitter: SplitEvery<String, char> = "a,b".to_string().split_every_n_times(',', 1);
+    assert_eq!(splitter.next_back().unwrap(), "b");
+    let json: serde_json::Value = serde_json::to_value(&splitter).unwrap();
+    for (field, value) in [
+        ("back", serde_json::json!(4)),
+        ("ind", serde_json::json!(2)),
+        ("ind", serde_json::json!(usize::MAX)),
+        ("back", serde_json::json!(0)),
+        ("max_splits", serde_json::json!(1)),
+    ] {
+        let mut json: serde_json::Value = json.clone();
+        json[field] = value;
+        assert!(serde_json::from_value::<SplitEvery<String, char>>(json).is_err());
+    }
+    let splitter: SplitEvery<String, char> = "é,a".to_string().split_every_n_times(',', 1);
+    let json: serde_json::Value = serde_json::to_value(&splitter).unwrap();
+    for (field, value) in [("ind", 1), ("back", 3)] {
+        let mut json: serde_json::Value = json.clone();
+        json[field] = serde_json::json!(value);
+        assert!(serde_json::from_value::<SplitEvery<String, char>>(json).is_err());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read() {
+    use std::io::Cursor;
+
+    let input: &[u8] = b"a--b--c--d--e-";
+    for buffer_size in 1..=input.len() + 1 {
+        let splitter: SplitEveryRead<Cursor<&[u8]>> =
+            SplitEvery::n_times_from_read(Cursor::new(input), b"--".to_vec(), 2)
+                .with_buffer_size(buffer_size);
+        assert_eq!(
+            splitter.collect::<io::Result<Vec<Vec<u8>>>>().unwrap(),
+            vec![b"a--b".to_vec(), b"c--d".to_vec(), b"e-".to_vec()]
+        );
+    }
+
+    let splitter: SplitEveryRead<Cursor<&[u8]>> =
+        SplitEvery::n_times_from_read(Cursor::new(&b",a,,b,"[..]), b",".to_vec(), 1)
+            .with_buffer_size(2);
+    assert_eq!(
+        splitter.collect::<io::Result<Vec<Vec<u8>>>>().unwrap(),
+        vec![b"".to_vec(), b"a".to_vec(), b"".to_vec(), b"b".to_vec()]
+    );
+
+    let mut splitter: SplitEveryRead<Cursor<&[u8]>> =
+        SplitEvery::n_times_from_read(Cursor::new(&b""[..]), b",".to_vec(), 1);
+    assert!(splitter.next().is_none());
+
+    struct Failing(bool);
+    impl Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if std::mem::replace(&mut self.0, true) {
+                return Ok(0);
+            }
+            buf[0] = b'x';
+            Err(io::Error::other("failed"))
+        }
+    }
+    let mut splitter: SplitEveryRead<Failing> =
+        SplitEvery::n_times_from_read(Failing(false), b",".to_vec(), 1);
+    assert!(splitter.next().unwrap().is_err());
+    assert!(splitter.next().is_none());
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn test_stream() {
+    use alloc::vec;
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    let splitter = SplitEveryStreamImpl::split_every_n_times(
+        stream::iter(["a", "b", "a", "c", "a", "d"]),
+        "a",
+        2,
+    );
+    assert_eq!(
+        block_on(splitter.collect::<Vec<Vec<&str>>>()),
+        vec![vec!["a", "b"], vec!["c", "a", "d"]]
+    );
+
+    // Yields `Pending` before every item to exercise buffering across polls.
+    let mut items = [1, 0, 1, 1, 0, 1, 0].into_iter();
+    let mut pending: bool = false;
+    let paced = stream::poll_fn(move |cx| {
+        pending = !pending;
+        if pending {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        Poll::Ready(items.next())
+    });
+    let mut splitter = SplitEveryStreamImpl::split_every_n_times(paced, 0, 1);
+    assert_eq!(block_on(splitter.next()), Some(vec![1]));
+    assert_eq!(block_on(splitter.next()), Some(vec![1, 1]));
+    assert_eq!(block_on(splitter.next()), Some(vec![1]));
+    assert_eq!(block_on(splitter.next()), None);
+    assert!(splitter.is_terminated());
+    assert_eq!(block_on(splitter.next()), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_count() {
+    let splitter: SplitEvery<&str, &str> = "a b c d e f g".split_every_n_times(" ", 2);
+    assert_eq!(
+        splitter.clone().count(),
+        splitter.collect::<Vec<String>>().len()
+    );
+
+    let mut splitter: SplitEvery<String, &str> = ",a,,b,".to_string().split_every_n_times(",", 1);
+    splitter.next();
+    assert_eq!(
+        splitter.clone().count(),
+        splitter.collect::<Vec<String>>().len()
+    );
+
+    let splitter: SplitEvery<Vec<u8>, &[u8]> =
+        vec![1, 0, 1, 0, 1, 0].split_every_n_times(&[0][..], 1);
+    assert_eq!(splitter.clone().count(), 3);
+    assert_eq!(
+        splitter.clone().count(),
+        splitter.collect::<Vec<Vec<u8>>>().len()
+    );
+
+    let splitter: SplitEvery<&[u8], &[u8]> = [1, 0, 1].as_slice().split_every_n_times(&[0][..], 1);
+    assert_eq!(splitter.with_max_splits(1).count(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_nth() {
+    let splitter: SplitEvery<&str, &str> = "a b c d e f g h i".split_every_n_times(" ", 2);
+    assert_eq!(
+        splitter.clone().nth(3),
+        splitter.clone().collect::<Vec<_>>().into_iter().nth(3)
+    );
+    assert_eq!(splitter.clone().nth(3).unwrap(), "g h");
+    assert_eq!(splitter.clone().nth(5), None);
+
+    let mut splitter: SplitEvery<String, &str> = "a b c d".to_string().split_every_n_times(" ", 1);
+    assert_eq!(splitter.nth(1).unwrap(), "b");
+    assert_eq!(splitter.next().unwrap(), "c");
+
+    let splitter: SplitEvery<Vec<u8>, &[u8]> =
+        vec![1, 0, 2, 0, 3, 0, 4, 0, 5].split_every_n_times(&[0][..], 1);
+    assert_eq!(
+        splitter.clone().nth(3),
+        splitter.clone().collect::<Vec<_>>().into_iter().nth(3)
+    );
+    assert_eq!(splitter.clone().nth(3).unwrap(), vec![4]);
+
+    let splitter: SplitEvery<&[u8], &[u8]> =
+        [1, 0, 2, 0, 3].as_slice().split_every_n_times(&[0][..], 2);
+    assert_eq!(
+        splitter.clone().nth(1),
+        splitter.clone().collect::<Vec<_>>().into_iter().nth(1)
+    );
+    assert_eq!(splitter.clone().nth(2), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_once() {
+    let mut splitter: SplitEvery<String, char> = "a.b..c".to_string().split_every_once('.');
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next().unwrap(), "b");
+    assert_eq!(splitter.next().unwrap(), "");
+    assert_eq!(splitter.next().unwrap(), "c");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<Vec<u8>, &[u8]> = vec![1, 0, 2].split_every_once(&[0][..]);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter = [1, 0, 2, 0, 3].into_iter().split_every_once(0);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_elem() {
+    let mut splitter: SplitEvery<Vec<i32>, [i32; 1]> =
+        vec![1, 2, 3, 2, 4, 2, 5].split_every_n_times_elem(2, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 2, 3]);
+    assert_eq!(splitter.next().unwrap(), vec![4, 2, 5]);
+    assert_eq!(splitter.next(), None);
+
+    let input: &[i32] = &[1, 2, 3, 2, 4, 2, 5];
+    let mut splitter: SplitEvery<&[i32], [i32; 1]> = input.split_every_n_times_elem(2, 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next().unwrap(), vec![4]);
+    assert_eq!(splitter.next().unwrap(), vec![5]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ranges() {
+    let input: &str = "a€b€€c";
+    let mut splitter: SplitEveryRanges<&str, char> = input.split_every_n_times_ranges('€', 1);
+    assert_eq!(splitter.next().unwrap(), 0..1);
+    assert_eq!(splitter.next_back().unwrap(), 11..12);
+    assert_eq!(splitter.next().unwrap(), 4..5);
+    assert_eq!(splitter.next().unwrap(), 8..8);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(
+        input
+            .split_every_n_times_ranges('€', 2)
+            .map(|range| &input[range])
+            .collect::<Vec<&str>>(),
+        input.split_every_n_times_ref('€', 2).collect::<Vec<&str>>()
+    );
+
+    let input: Vec<u8> = vec![1, 0, 2, 0, 0, 3];
+    let splitter: SplitEveryRanges<Vec<u8>, [u8; 1]> =
+        input.clone().split_every_n_times_ranges([0], 1);
+    assert_eq!(
+        splitter.collect::<Vec<Range<usize>>>(),
+        vec![0..1, 2..3, 4..4, 5..6]
+    );
+
+    let splitter: SplitEveryRanges<&[u8], u8> = input.as_slice().split_every_n_times_ranges(0, 3);
+    assert_eq!(splitter.collect::<Vec<Range<usize>>>(), vec![0..4, 5..6]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_short_circuit() {
+    use core::ops::ControlFlow;
+
+    let mut splitter: SplitEvery<&str, &str> = "a b ccc d eeeee f".split_every_n_times(" ", 1);
+    assert_eq!(splitter.find(|chunk| chunk.len() > 2).unwrap(), "ccc");
+    assert_eq!(splitter.remainder(), "d eeeee f");
+    assert!(splitter.any(|chunk| chunk.len() > 4));
+    assert_eq!(splitter.next().unwrap(), "f");
+
+    let mut splitter: SplitEvery<&[u8], u8> = [1, 0, 2, 2, 0, 3, 0, 4]
+        .as_slice()
+        .split_every_n_times(0, 1);
+    let flow: ControlFlow<Vec<u8>, usize> = splitter.try_fold(0, |seen, chunk| {
+        if chunk.len() > 1 {
+            return ControlFlow::Break(chunk);
+        }
+        ControlFlow::Continue(seen + 1)
+    });
+    assert_eq!(flow, ControlFlow::Break(vec![2, 2]));
+    assert_eq!(splitter.remainder(), &[3, 0, 4]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_last() {
+    for input in ["a b c d e", "a b c d ", "a", "", " ", "a b"] {
+        let splitter: SplitEvery<&str, &str> = input.split_every_n_times(" ", 2);
+        assert_eq!(
+            splitter.clone().last(),
+            splitter.collect::<Vec<String>>().pop()
+        );
+        let splitter: SplitEvery<String, char> = input.to_string().split_every_n_times(' ', 1);
+        assert_eq!(
+            splitter.clone().last(),
+            splitter.collect::<Vec<String>>().pop()
+        );
+    }
+
+    let splitter: SplitEvery<Vec<u8>, u8> = vec![1, 0, 2, 0, 3, 0].split_every_n_times(0, 2);
+    assert_eq!(
+        splitter.clone().last(),
+        splitter.collect::<Vec<Vec<u8>>>().pop()
+    );
+    let splitter: SplitEvery<&[u8], u8> = [1, 0, 2, 0, 3].as_slice().split_every_n_times(0, 1);
+    assert_eq!(splitter.clone().last(), Some(vec![3]));
+    assert_eq!(splitter.with_max_splits(2).last(), Some(vec![2, 0, 3]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ignore_ascii_case() {
+    let mut splitter: SplitEvery<&str, IgnoreAsciiCase<&str>> =
+        "x AND y and z And w aNd v".split_every_n_times_ignore_ascii_case(" and ", 2);
+    assert_eq!(splitter.next().unwrap(), "x AND y");
+    assert_eq!(splitter.next().unwrap(), "z And w");
+    assert_eq!(splitter.next().unwrap(), "v");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<String, IgnoreAsciiCase<String>> = "ÄxäXä"
+        .to_string()
+        .split_every_n_times_ignore_ascii_case("x".to_string(), 1);
+    assert_eq!(splitter.next_back().unwrap(), "ä");
+    assert_eq!(splitter.next().unwrap(), "Ä");
+    assert_eq!(splitter.next().unwrap(), "ä");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEvery<&str, IgnoreAsciiCase<&str>> =
+        "ÄäÄ".split_every_n_times_ignore_ascii_case("ä", 1);
+    assert_eq!(splitter.next().unwrap(), "Ä");
+    assert_eq!(splitter.next().unwrap(), "Ä");
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_overlapping() {
+    let splitter: SplitEvery<&str, &str> = "aaaa".split_every_n_times("aa", 2);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["aa"]);
+    let splitter: SplitEvery<&str, &str> = "aaaa".split_every_n_times("aa", 2).overlapping(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["aaa", "a"]);
+    let splitter: SplitEvery<&str, &str> = "aaaa".split_every_n_times("aa", 3).overlapping(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["aaaa"]);
+    let splitter: SplitEvery<&str, &str> = "aaaa".split_every_n_times("aa", 1).overlapping(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["aa", "aa"]);
+
+    let mut splitter: SplitEvery<&str, &str> =
+        "ééébéé".split_every_n_times("éé", 2).overlapping(true);
+    assert_eq!(splitter.clone().next_back().unwrap(), "béé");
+    assert_eq!(splitter.next().unwrap(), "ééé");
+    assert_eq!(splitter.next().unwrap(), "béé");
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryRef<&str, &str> = "xaaaxaaa"
+        .split_every_n_times_ref("aa", 2)
+        .overlapping(true);
+    assert_eq!(splitter.next_back().unwrap(), "xaaa");
+    assert_eq!(splitter.next_back().unwrap(), "xaaa");
+    assert_eq!(splitter.next(), None);
+
+    let splitter: SplitEvery<&[u8], &[u8]> = [1, 1, 1, 1, 2]
+        .as_slice()
+        .split_every_n_times(&[1, 1][..], 2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 1], vec![2]]
+    );
+    let splitter: SplitEvery<&[u8], &[u8]> = [1, 1, 1, 1, 2]
+        .as_slice()
+        .split_every_n_times(&[1, 1][..], 2)
+        .overlapping(true);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 1, 1], vec![1, 2]]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reset() {
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 2);
+    let expected: Vec<String> = splitter.by_ref().collect();
+    splitter.reset();
+    assert_eq!(splitter.by_ref().collect::<Vec<String>>(), expected);
+    splitter.next_back();
+    splitter.reset();
+    assert_eq!(splitter.by_ref().collect::<Vec<String>>(), expected);
+
+    let mut splitter: SplitEvery<String, char> = "a,b,".to_string().split_every_n_times(',', 1);
+    assert_eq!(splitter.next_back().unwrap(), "b");
+    splitter.reset();
+    assert_eq!(splitter.by_ref().collect::<Vec<String>>(), vec!["a", "b"]);
+
+    let mut splitter: SplitEvery<Vec<u8>, u8> = vec![1, 0, 2, 0, 3]
+        .split_every_n_times(0, 1)
+        .with_max_splits(2);
+    assert_eq!(splitter.by_ref().count(), 2);
+    splitter.reset();
+    assert_eq!(
+        splitter.by_ref().collect::<Vec<Vec<u8>>>(),
+        vec![vec![1], vec![2, 0, 3]]
+    );
+
+    let mut splitter: SplitEveryRef<&str, &str> = "a b".split_every_n_times_ref(" ", 1);
+    assert_eq!(splitter.by_ref().count(), 2);
+    splitter.reset();
+    assert_eq!(splitter.next().unwrap(), "a");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_counted() {
+    let mut splitter: SplitEveryCounted<&str, &str> =
+        "a b c d e f g h".split_every_n_times_counted(" ", 3);
+    assert_eq!(splitter.next().unwrap(), ("a b c".to_string(), 3));
+    assert_eq!(splitter.next().unwrap(), ("d e f".to_string(), 3));
+    assert_eq!(splitter.next().unwrap(), ("g h".to_string(), 1));
+    assert_eq!(splitter.next(), None);
+
+    let mut splitter: SplitEveryCounted<String, char> =
+        "a,b,".to_string().split_every_n_times_counted(',', 2);
+    assert_eq!(splitter.next().unwrap(), ("a,b".to_string(), 2));
+    assert_eq!(splitter.next(), None);
+
+    let splitter: SplitEveryCounted<Vec<u8>, u8> =
+        vec![1, 0, 2, 0, 3, 0, 4].split_every_n_times_counted(0, 2);
+    assert_eq!(
+        splitter.collect::<Vec<(Vec<u8>, usize)>>(),
+        vec![(vec![1, 0, 2], 2), (vec![3, 0, 4], 1)]
+    );
+
+    let splitter: SplitEveryCounted<&[u8], u8> =
+        [1, 2].as_slice().split_every_n_times_counted(0, 2);
+    assert_eq!(
+        splitter.collect::<Vec<(Vec<u8>, usize)>>(),
+        vec![(vec![1, 2], 0)]
+    );
+
+    let splitter: SplitEveryCounted<&[u8], u8> = [1, 0, 2, 0, 3]
+        .as_slice()
+        .split_every_n_times_counted(0, 1)
+        .with_max_splits(2);
+    assert_eq!(
+        splitter.collect::<Vec<(Vec<u8>, usize)>>(),
+        vec![(vec![1], 1), (vec![2, 0, 3], 0)]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_collect_chunks_into() {
+    use std::collections::VecDeque;
+
+    let mut splitter: SplitEveryInto<Vec<u8>, u8, VecDeque<u8>> = vec![1, 0, 2, 0, 3]
+        .split_every_n_times(0, 1)
+        .collect_chunks_into();
+    assert_eq!(splitter.next().unwrap(), VecDeque::from([1]));
+    assert_eq!(splitter.next().unwrap(), VecDeque::from([2]));
+    assert_eq!(splitter.next().unwrap(), VecDeque::from([3]));
+    assert_eq!(splitter.next(), None);
+
+    let splitter = [1, 0, 2, 0, 3]
+        .into_iter()
+        .split_every_n_times_inclusive(0, 2)
+        .collect_chunks_into::<VecDeque<u8>>();
+    assert_eq!(
+        splitter.collect::<Vec<VecDeque<u8>>>(),
+        vec![VecDeque::from([1, 0, 2, 0]), VecDeque::from([3])]
+    );
+
+    let splitter: SplitEvery<&[u8], &[u8]> =
+        b"a, b, c".as_slice().split_every_n_times(&b", "[..], 2);
+    assert_eq!(
+        splitter
+            .clone()
+            .collect_chunks_into::<VecDeque<u8>>()
+            .map(Vec::from)
+            .collect::<Vec<Vec<u8>>>(),
+        splitter.collect::<Vec<Vec<u8>>>()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_trailing_empty() {
+    for (input, expected) in [
+        ("a b ", vec!["a", "b"]),
+        (" a  b", vec!["", "a", "", "b"]),
+        (" a b ", vec!["", "a", "b"]),
+        ("  ", vec!["", ""]),
+        (" ", vec![""]),
+        ("", vec![]),
+    ] {
+        assert_eq!(
+            input.split_every_n_times(" ", 1).collect::<Vec<String>>(),
+            expected
+        );
+        assert_eq!(
+            input
+                .to_string()
+                .split_every_n_times(' ', 1)
+                .collect::<Vec<String>>(),
+            expected
+        );
+        let mut reversed: Vec<&str> = input.split_every_n_times_ref(" ", 1).rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+        assert_eq!(input.split_terminator(' ').collect::<Vec<&str>>(), expected);
+
+        let bytes: Vec<u8> = input.bytes().collect();
+        let expected: Vec<Vec<u8>> = expected
+            .iter()
+            .map(|chunk| chunk.as_bytes().to_vec())
+            .collect();
+        assert_eq!(
+            bytes
+                .as_slice()
+                .split_every_n_times(b' ', 1)
+                .collect::<Vec<Vec<u8>>>(),
+            expected
+        );
+        assert_eq!(
+            bytes
+                .clone()
+                .split_every_n_times(vec![b' '], 1)
+                .collect::<Vec<Vec<u8>>>(),
+            expected
+        );
+        assert_eq!(
+            bytes
+                .into_iter()
+                .split_every_n_times(b' ', 1)
+                .collect::<Vec<Vec<u8>>>(),
+            expected
+        );
+    }
+
+    let splitter: SplitEvery<&str, &str> = ",a,b,c,".split_every_n_times(",", 2);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec![",a", "b,c"]);
+    let splitter = ",a,b,c,".chars().split_every_n_times(',', 2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<char>>>(),
+        vec![vec![',', 'a'], vec!['b', ',', 'c']]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "pattern returned an invalid match")]
+fn test_invalid_pattern() {
+    struct Bogus;
+    impl StrPattern for Bogus {
+        fn find_in(&self, _: &str) -> Option<Range<usize>> {
+            Some(1..2)
+        }
+    }
+    "€".split_every_n_times(Bogus, 1).for_each(drop);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "pattern returned an invalid match")]
+fn test_invalid_arr_pattern() {
+    struct Bogus;
+    impl ArrPattern<u8> for Bogus {
+        fn find_in(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+            Some(0..haystack.len() + 1)
+        }
+    }
+    [1, 2].split_every_n_times(Bogus, 1).for_each(drop);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cow() {
+    for input in [
+        Cow::Borrowed("a b c d e"),
+        Cow::Owned("a b c d e".to_string()),
+    ] {
+        let mut splitter: SplitEvery<Cow<str>, &str> = input.split_every_n_times(" ", 2);
+        assert_eq!(splitter.next().unwrap(), "a b");
+        assert_eq!(splitter.remainder(), "c d e");
+        assert_eq!(splitter.next_back().unwrap(), "e");
+        assert_eq!(splitter.next().unwrap(), "c d");
+        assert_eq!(splitter.next(), None);
+        assert_eq!(
+            format!("{splitter:?}"),
+            r#"SplitEvery { input: "a b c d e", pat: " ", n: 2, ind: 4, .. }"#
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_borrowed_string() {
+    let mut input: String = "a b c d e".to_string();
+    let mut splitter: SplitEvery<&mut String, &str> = (&mut input).split_every_n_times(" ", 2);
+    assert_eq!(splitter.next().unwrap(), "a b");
+    assert_eq!(splitter.remainder(), "c d e");
+    assert_eq!(splitter.next_back().unwrap(), "e");
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["c d"]);
+    input.push_str(" f");
+    assert_eq!(input, "a b c d e f");
+
+    let chunks: Vec<String> = (&input).split_every_n_times(' ', 3).collect();
+    assert_eq!(chunks, vec!["a b c", "d e f"]);
+    assert_eq!(input, "a b c d e f");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_enumerate_chunks() {
+    let splitter: SplitEveryEnumerate<&str, &str> = "a b c d e f g h"
+        .split_every_n_times(" ", 3)
+        .enumerate_chunks();
+    assert_eq!(
+        splitter.collect::<Vec<(usize, String)>>(),
+        vec![
+            (3, "a b c".to_string()),
+            (6, "d e f".to_string()),
+            (7, "g h".to_string())
+        ]
+    );
+
+    let splitter: SplitEveryEnumerate<&[u8], u8> = [1, 0, 0, 2, 0, 3, 0, 0, 0]
+        .as_slice()
+        .split_every_n_times(0, 2)
+        .enumerate_chunks();
+    let totals: Vec<usize> = splitter.map(|(total, _)| total).collect();
+    assert_eq!(totals, vec![2, 4, 6]);
+    assert!(totals
+        .windows(2)
+        .all(|pair| pair[0] < pair[1] && pair[1] - pair[0] <= 2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_borrowed_pattern() {
+    let pat: Vec<u8> = vec![0, 0];
+    let mut splitter: SplitEvery<Vec<u8>, &[u8]> =
+        vec![1, 0, 0, 2, 0, 0, 3].split_every_n_times(pat.as_slice(), 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.remainder(), &[2, 0, 0, 3]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(pat, vec![0, 0]);
+
+    let input: &[u8] = &[1, 0, 2];
+    let mut splitter: SplitEvery<&[u8], Vec<u8>> = input.split_every_n_times_inclusive(vec![0], 1);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_array() {
+    let mut splitter: SplitEvery<[u8; 5], [u8; 1]> = [1, 0, 2, 0, 3].split_every_n_times([0], 1);
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.remainder(), &[2, 0, 3]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+
+    let splitter: SplitEvery<[u8; 5], By<_>> =
+        [1, 0, 2, 0, 3].split_every_n_times_by(|val| *val == 0, 2);
+    assert_eq!(
+        format!("{:?}", splitter.clone().into_inner().0),
+        "[1, 0, 2, 0, 3]"
+    );
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 0, 2], vec![3]]
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par() {
+    let input: Vec<u8> = (0..10_000u32)
+        .map(|val| (val * 7919 % 5) as u8)
+        .chain([0, 0, 0, 0])
+        .collect();
+    for pat in [&[0][..], &[0, 0], &[1, 2, 3], &[], &[9]] {
+        for n in [0, 1, 2, 3, 7] {
+            assert_eq!(
+                input
+                    .as_slice()
+                    .par_split_every_n_times(pat, n)
+                    .map(<[u8]>::to_vec)
+                    .collect::<Vec<Vec<u8>>>(),
+                input
+                    .as_slice()
+                    .split_every_n_times(pat, n)
+                    .collect::<Vec<Vec<u8>>>()
+            );
+        }
+    }
+    let empty: &[u8] = &[];
+    assert_eq!(empty.par_split_every_n_times(&[0], 1).count(), 0);
+    assert_eq!(
+        b"aaaa".as_slice().par_split_every_n_times(b"aa", 1).len(),
+        2
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_regex() {
+    let re: regex::Regex = regex::Regex::new(r"\s+").unwrap();
+    let splitter: SplitEvery<&str, &regex::Regex> =
+        "a b \t c\n\nd  e f".split_every_n_times_regex(&re, 2);
+    assert_eq!(
+        splitter.collect::<Vec<String>>(),
+        vec!["a b", "c\n\nd", "e f"]
+    );
+    let splitter: SplitEvery<String, &regex::Regex> =
+        String::from("a b \t c\n\nd  e f").split_every_n_times_regex(&re, 2);
+    assert_eq!(
+        splitter.rev().collect::<Vec<String>>(),
+        vec!["e f", "c\n\nd", "a b"]
+    );
+    let re: regex::Regex = regex::Regex::new("cat|dog|bird").unwrap();
+    let splitter: SplitEvery<&str, &regex::Regex> =
+        "1cat2dog3bird4cat5".split_every_n_times_regex(&re, 2);
+    assert_eq!(
+        splitter.collect::<Vec<String>>(),
+        vec!["1cat2", "3bird4", "5"]
+    );
+    // Empty matches are skipped instead of looping forever.
+    let re: regex::Regex = regex::Regex::new("a*").unwrap();
+    let splitter: SplitEvery<&str, &regex::Regex> = "baabaaab".split_every_n_times_regex(&re, 1);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["b", "b", "b"]);
+    let re: regex::Regex = regex::Regex::new(r"\b").unwrap();
+    let splitter: SplitEvery<&str, &regex::Regex> = "a b".split_every_n_times_regex(&re, 1);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["a b"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_peek_chunk() {
+    let mut splitter: SplitEvery<&str, &str> = "a,b,c,d,e".split_every_n_times(",", 2);
+    assert_eq!(splitter.peek_chunk(), Some("a,b"));
+    assert_eq!(splitter.peek_chunk(), Some("a,b"));
+    assert_eq!(splitter.next().unwrap(), "a,b");
+    assert_eq!(splitter.peek_chunk(), Some("c,d"));
+    assert_eq!(splitter.next_back().unwrap(), "e");
+    assert_eq!(splitter.peek_chunk(), Some("c,d"));
+    assert_eq!(splitter.next().unwrap(), "c,d");
+    assert_eq!(splitter.peek_chunk(), None);
+    assert_eq!(splitter.next(), None);
+    let mut splitter: SplitEvery<String, char> = String::from(",a,b,")
+        .split_every_once(',')
+        .with_max_splits(2);
+    assert_eq!(splitter.peek_chunk(), Some(""));
+    assert_eq!(splitter.next().unwrap(), "");
+    assert_eq!(splitter.peek_chunk(), Some("a,b,"));
+    assert_eq!(splitter.next().unwrap(), "a,b,");
+    assert_eq!(splitter.peek_chunk(), None);
+    let input: [u8; 7] = [1, 0, 2, 0, 3, 0, 0];
+    let mut splitter: SplitEvery<&[u8], u8> = input.as_slice().split_every_once(0);
+    let mut peeked: Vec<Vec<u8>> = Vec::new();
+    while let Some(chunk) = splitter.peek_chunk() {
+        peeked.push(chunk.to_vec());
+        assert_eq!(splitter.next().unwrap(), chunk);
+    }
+    assert_eq!(peeked, vec![vec![1], vec![2], vec![3], vec![]]);
+    let mut splitter: SplitEvery<Vec<u8>, [u8; 1]> = vec![1, 0, 2].split_every_n_times([0], 1);
+    assert_eq!(splitter.peek_chunk(), Some([1].as_slice()));
+    assert_eq!(splitter.next().unwrap(), vec![1]);
+    assert_eq!(splitter.peek_chunk(), Some([2].as_slice()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_drain() {
+    let mut input: Vec<u8> = vec![9, 1, 0, 2, 0, 3, 0, 4];
+    let mut splitter: SplitEvery<alloc::vec::Drain<u8>, u8> =
+        SplitEveryImpl::split_every_n_times(input.drain(1..), 0, 2);
+    assert_eq!(splitter.next().unwrap(), vec![1, 0, 2]);
+    assert_eq!(splitter.remainder(), &[3, 0, 4]);
+    assert_eq!(splitter.next().unwrap(), vec![3, 0, 4]);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.remainder(), &[] as &[u8]);
+    drop(splitter);
+    assert_eq!(input, vec![9]);
+
+    let mut input: Vec<&str> = vec!["a", ",", ",", "b", ","];
+    let splitter: SplitEvery<alloc::vec::Drain<&str>, &str> =
+        SplitEveryImpl::split_every_n_times(input.drain(..), ",", 1);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<&str>>>(),
+        vec![vec!["a"], vec![], vec!["b"]]
+    );
+    assert!(input.is_empty());
+
+    // Elements past a chunk limit are drained into the final chunk.
+    let mut input: Vec<u8> = vec![1, 0, 2, 0, 3];
+    let splitter: SplitEvery<alloc::vec::Drain<u8>, u8> =
+        SplitEveryImpl::split_every_n_times(input.drain(..), 0, 1).with_max_splits(2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1], vec![2, 0, 3]]
+    );
+    assert!(input.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_builder() {
+    let splitter: SplitEvery<&str, &str> = SplitEvery::builder("a,b,c,d,e")
+        .pattern(",")
+        .every(2)
+        .build();
+    assert_eq!(
+        splitter.collect::<Vec<String>>(),
+        "a,b,c,d,e"
+            .split_every_n_times(",", 2)
+            .collect::<Vec<String>>()
+    );
+
+    let mut splitter: SplitEvery<&str, &str> = SplitEvery::builder("a,b,c,d,e")
+        .pattern(",")
+        .inclusive(true)
+        .max_splits(3)
+        .build();
+    assert_eq!(splitter.next().unwrap(), "a,");
+    assert_eq!(splitter.next().unwrap(), "b,");
+    assert_eq!(splitter.next().unwrap(), "c,d,e");
+    assert_eq!(splitter.next(), None);
+
+    let splitter: SplitEvery<String, IgnoreAsciiCase<&str>> =
+        SplitEvery::builder("xAAaxaAAa".to_string())
+            .pattern("aa")
+            .every(2)
+            .overlapping(true)
+            .ignore_ascii_case()
+            .build();
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["xAAa", "xaAA", "a"]);
+
+    let splitter: SplitEvery<Vec<u8>, u8> = SplitEvery::builder(vec![1, 0, 2, 0, 3, 0, 4])
+        .pattern(0)
+        .every(2)
+        .inclusive(true)
+        .max_splits(1)
+        .build();
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 0, 2, 0, 3, 0, 4]]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_skip_empty() {
+    let splitter: SplitEvery<&str, &str> = "a,,b,,,c,".split_every_n_times(",", 1).skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["a", "b", "c"]);
+    let splitter: SplitEvery<&str, &str> = "a,,b,,,c,".split_every_n_times(",", 1).skip_empty(true);
+    assert_eq!(splitter.rev().collect::<Vec<String>>(), vec!["c", "b", "a"]);
+    let splitter: SplitEvery<&str, &str> = ",,,".split_every_n_times(",", 1).skip_empty(true);
+    assert_eq!(splitter.size_hint().0, 0);
+    assert_eq!(splitter.count(), 0);
+
+    // Skipped chunks still consume their patterns.
+    let splitter: SplitEvery<&str, &str> = "a,,b,c".split_every_n_times(",", 2).skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["a,", "b,c"]);
+    let splitter: SplitEveryEnumerate<&str, &str> = ",,a,b"
+        .split_every_n_times(",", 1)
+        .skip_empty(true)
+        .enumerate_chunks();
+    assert_eq!(
+        splitter.collect::<Vec<(usize, String)>>(),
+        vec![(3, "a".to_string()), (3, "b".to_string())]
+    );
+
+    // Skipped chunks do not count against the chunk limit.
+    let splitter: SplitEvery<String, char> = ",,a,,b,c"
+        .to_string()
+        .split_every_n_times(',', 1)
+        .skip_empty(true)
+        .with_max_splits(2);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["a", ",b,c"]);
+
+    let splitter: SplitEveryRef<&str, &str> =
+        "a  b   c".split_every_n_times_ref(" ", 1).skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<&str>>(), vec!["a", "b", "c"]);
+
+    let splitter: SplitEvery<Vec<u8>, u8> = vec![0, 1, 0, 0, 2, 0]
+        .split_every_n_times(0, 1)
+        .skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), vec![vec![1], vec![2]]);
+    let splitter: SplitEvery<&[u8], &[u8]> = [0, 1, 0, 0, 2]
+        .as_slice()
+        .split_every_n_times(&[0][..], 1)
+        .skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), vec![vec![1], vec![2]]);
+    let splitter = [0, 1, 0, 0, 2, 0]
+        .into_iter()
+        .split_every_n_times(0, 1)
+        .skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), vec![vec![1], vec![2]]);
+    let splitter = [0, 0, 1, 0, 0, 0, 2]
+        .into_iter()
+        .split_every_n_times(0, 1)
+        .skip_empty(true)
+        .with_max_splits(2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<u8>>>(),
+        vec![vec![1], vec![0, 0, 2]]
+    );
+
+    let splitter: SplitEvery<&str, &str> = SplitEvery::builder("a;;b")
+        .pattern(";")
+        .skip_empty(true)
+        .build();
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["a", "b"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_occurrences_left() {
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e f g".split_every_n_times(" ", 2);
+    let mut left: usize = splitter.occurrences_left();
+    assert_eq!(left, 6);
+    while splitter.next().is_some() {
+        let now: usize = splitter.occurrences_left();
+        assert!(left - now <= 2);
+        left = now;
+    }
+    assert_eq!(left, 0);
+
+    let mut splitter: SplitEvery<String, char> = "a,b,c".to_string().split_every_once(',');
+    assert_eq!(splitter.next_back().unwrap(), "c");
+    assert_eq!(splitter.occurrences_left(), 1);
+    let splitter: SplitEvery<&str, &str> = "aaaa".split_every_n_times("aa", 1);
+    assert_eq!(splitter.occurrences_left(), 2);
+    assert_eq!(splitter.overlapping(true).occurrences_left(), 3);
+
+    let mut splitter: SplitEvery<&[u8], u8> =
+        [1, 0, 2, 0, 0, 3].as_slice().split_every_n_times(0, 2);
+    assert_eq!(splitter.occurrences_left(), 3);
+    splitter.next();
+    assert_eq!(splitter.occurrences_left(), 1);
+    splitter.next();
+    assert_eq!(splitter.occurrences_left(), 0);
+
+    let mut splitter: SplitEvery<Vec<u8>, Vec<u8>> =
+        vec![1, 0, 0, 0, 0, 2].split_every_n_times(vec![0, 0], 1);
+    assert_eq!(splitter.occurrences_left(), 2);
+    splitter.next();
+    assert_eq!(splitter.occurrences_left(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_deque() {
+    // Wrap the deque around its buffer, with a `[0, 0]` pattern straddling the end.
+    let mut input: VecDeque<u8> = VecDeque::with_capacity(8);
+    input.extend([9, 9, 9, 9, 9, 9]);
+    input.drain(..5);
+    input.extend([1, 0, 0, 2, 0, 0, 3]);
+    assert!(!input.as_slices().1.is_empty());
+    assert_eq!(input.as_slices().0.last(), Some(&0));
+    let expected: Vec<Vec<u8>> = Vec::from(input.clone())
+        .split_every_n_times(vec![0, 0], 1)
+        .collect();
+    let mut splitter: SplitEvery<VecDeque<u8>, Vec<u8>> = input.split_every_n_times(vec![0, 0], 1);
+    assert_eq!(splitter.next().unwrap(), vec![9, 1]);
+    assert_eq!(splitter.next().unwrap(), vec![2]);
+    assert_eq!(splitter.next().unwrap(), vec![3]);
+    assert_eq!(splitter.next(), None);
+    splitter.reset();
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), expected);
+
+    let mut input: VecDeque<i32> = VecDeque::from([0, 4]);
+    input.push_front(3);
+    input.push_front(0);
+    let splitter: SplitEvery<VecDeque<i32>, By<_>> =
+        input.split_every_n_times_by(|val: &i32| *val == 0, 2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<i32>>>(),
+        vec![vec![0, 3], vec![4]]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ref_slice() {
+    let input: &[u8] = &[1, 2, 0, 3, 0, 4, 5, 0, 6];
+    let mut splitter: SplitEveryRef<&[u8], u8> = input.split_every_n_times_ref(0, 2);
+    let chunk: &[u8] = splitter.next().unwrap();
+    assert_eq!(chunk, &[1, 2, 0, 3]);
+    assert_eq!(chunk.as_ptr(), input.as_ptr());
+    assert_eq!(splitter.remainder(), &[4, 5, 0, 6]);
+    let chunk: &[u8] = splitter.next().unwrap();
+    assert_eq!(chunk, &[4, 5, 0, 6]);
+    assert!(core::ptr::eq(chunk, &input[5..]));
+    assert_eq!(splitter.next(), None);
+    splitter.reset();
+    assert_eq!(
+        splitter.map(<[u8]>::to_vec).collect::<Vec<Vec<u8>>>(),
+        input.split_every_n_times(0, 2).collect::<Vec<Vec<u8>>>()
+    );
+
+    let input: Vec<u16> = vec![7, 8, 9, 7, 8, 7, 8];
+    let chunks: Vec<&[u16]> = input
+        .as_slice()
+        .split_every_n_times_ref_inclusive(&[7, 8][..], 1)
+        .collect();
+    assert_eq!(chunks, vec![&[7, 8][..], &[9, 7, 8], &[7, 8]]);
+    let (whole, pat, n): (&[u16], &[u16], usize) = input
+        .as_slice()
+        .split_every_n_times_ref(&[7, 8][..], 1)
+        .with_max_splits(1)
+        .into_inner();
+    assert_eq!((whole, pat, n), (input.as_slice(), &[7, 8][..], 1));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_multibyte() {
+    /// Groups the pieces of `str::split` by `n`, dropping a trailing empty group.
+    fn expected(input: &str, pat: &str, n: usize) -> Vec<String> {
+        let pieces: Vec<&str> = input.split(pat).collect();
+        let mut groups: Vec<String> = pieces.chunks(n).map(|group| group.join(pat)).collect();
+        if groups.last().is_some_and(String::is_empty) && pieces.len() % n == 1 % n {
+            groups.pop();
+        }
+        groups
+    }
+
+    for input in [
+        "→a→→b→",
+        "🎉→🎉🎉→→漢字→かな→🇯🇵",
+        "漢→字→",
+        "→",
+        "日本語のテキスト",
+        "",
+    ] {
+        for pat in ["→", "🎉", "→🎉", "字"] {
+            for n in 1..=3 {
+                let chunks: Vec<String> = input.split_every_n_times(pat, n).collect();
+                assert_eq!(chunks, expected(input, pat, n), "{input:?} {pat:?} {n}");
+                let mut reversed: Vec<String> = input.split_every_n_times(pat, n).rev().collect();
+                reversed.reverse();
+                assert_eq!(reversed, chunks);
+                for range in input.split_every_n_times_ranges(pat, n) {
+                    assert!(
+                        input.is_char_boundary(range.start) && input.is_char_boundary(range.end)
+                    );
+                }
+                let first: char = pat.chars().next().unwrap();
+                let chunks: Vec<String> = input.to_string().split_every_n_times(first, n).collect();
+                assert_eq!(chunks, expected(input, first.encode_utf8(&mut [0; 4]), n));
+            }
+        }
+    }
+
+    let splitter: SplitEvery<&str, &str> = "🎉🎉🎉→🎉🎉"
+        .split_every_n_times("🎉🎉", 2)
+        .overlapping(true);
+    assert_eq!(splitter.collect::<Vec<String>>(), vec!["🎉🎉🎉", "→🎉🎉"]);
+    let splitter: SplitEvery<&str, char> =
+        "漢字、かな、カナ、🇯🇵".split_every_n_times_inclusive('、', 2);
+    assert_eq!(
+        splitter.collect::<Vec<String>>(),
+        vec!["漢字、かな、", "カナ、🇯🇵"]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_map_chunks() {
+    let input: &str = " a , b , c , d ";
+    let trimmed: Vec<&str> = input
+        .split_every_n_times(",", 2)
+        .map_chunks(str::trim)
+        .collect();
+    assert_eq!(trimmed, vec!["a , b", "c , d"]);
+    // The chunks are borrowed from the input rather than copied first, unlike with `map`.
+    assert!(core::ptr::eq(trimmed[0], &input[1..6]));
+    let mapped: Vec<String> = input
+        .split_every_n_times(",", 2)
+        .map(|chunk| chunk.trim().to_string())
+        .collect();
+    assert_eq!(mapped, trimmed);
+    assert!(!core::ptr::eq(mapped[0].as_str(), &input[1..6]));
+
+    let lens: Vec<usize> = "1 22 333"
+        .to_string()
+        .split_every_n_times(' ', 1)
+        .map_chunks(str::len)
+        .collect();
+    assert_eq!(lens, vec![1, 2, 3]);
+
+    let splitter = [1, 2, 0, 3, 0, 4, 5, 6_i32]
+        .as_slice()
+        .split_every_n_times([0], 1)
+        .map_chunks(|chunk: &[i32]| chunk.iter().sum::<i32>());
+    assert!(format!("{splitter:?}").ends_with("f: <fn> }"));
+    assert_eq!(splitter.collect::<Vec<i32>>(), vec![3, 3, 15]);
+    let lens: Vec<usize> = vec![1_u8, 0, 0, 2]
+        .split_every_n_times(0, 1)
+        .map_chunks(<[u8]>::len)
+        .collect();
+    assert_eq!(lens, vec![1, 0, 1]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_fold_chunks() {
+    let input: &str = "a,bb,ccc,dddd,";
+    let mut splitter: SplitEvery<&str, &str> = input.split_every_n_times(",", 2);
+    splitter.next();
+    assert_eq!(
+        splitter
+            .clone()
+            .fold_chunks(0, |total, chunk| total + chunk.len()),
+        splitter.fold(0, |total, chunk| total + chunk.len())
+    );
+    assert_eq!(
+        "a,bb".to_string().split_every_n_times(',', 1).fold_chunks(
+            String::new(),
+            |mut out, chunk| {
+                out.push_str(chunk);
+                out
+            }
+        ),
+        "abb"
+    );
+    assert_eq!(
+        vec![1_u8, 0, 2, 3, 0]
+            .split_every_n_times(0, 1)
+            .fold_chunks(0, |max, chunk| chunk.len().max(max)),
+        2
+    );
+}
+
+#[test]
+fn test_eq() {
+    let mut fresh: SplitEvery<&str, &str> = "a,b,c,d,e".split_every_n_times(",", 2);
+    let mut cloned: SplitEvery<&str, &str> = fresh.clone();
+    assert_eq!(fresh, cloned);
+    assert_eq!(fresh.next().as_deref(), Some("a,b"));
+    assert_ne!(fresh, cloned);
+    assert_eq!(cloned.next().as_deref(), Some("a,b"));
+    assert_eq!(fresh, cloned);
+    assert_eq!(fresh.next_back(), cloned.next_back());
+    assert_eq!(fresh, cloned);
+    assert_ne!(fresh, "a,b,c,d,e".split_every_n_times(",", 3));
+    assert_ne!(
+        [1, 0, 2].split_every_n_times([0], 1),
+        [1, 0, 2].split_every_n_times([2], 1)
+    );
+
+    // Options that change the chunks make iterators unequal.
+    let plain: SplitEvery<&str, &str> = SplitEvery::new("a, b,,c,", ",", 1);
+    for with_option in [
+        plain.clone().skip_empty(true),
+        plain.clone().trim_chunks(true),
+        plain.clone().lossless(true),
+        plain.clone().overlapping(true),
+        plain.clone().with_max_splits(2),
+        plain.clone().keep_trailing_empty(true),
+        plain.clone().min_chunk_len(2),
+    ] {
+        assert_ne!(plain, with_option);
+        assert_ne!(
+            plain.clone().collect::<Vec<String>>(),
+            with_option.collect::<Vec<String>>()
+        );
+    }
+    assert_ne!(plain, plain.clone().require_pattern(true));
+    assert_eq!(plain, plain.clone().skip_empty(false));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_zero_n() {
+    let whole: Vec<&str> = vec!["a,b,,c,"];
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times(",", 0)
+            .collect::<Vec<String>>(),
+        whole
+    );
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times(",", 0)
+            .rev()
+            .collect::<Vec<String>>(),
+        whole
+    );
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times_ref(",", 0)
+            .collect::<Vec<&str>>(),
+        whole
+    );
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times_ref(",", 0)
+            .rev()
+            .collect::<Vec<&str>>(),
+        whole
+    );
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times_counted(",", 0)
+            .collect::<Vec<(String, usize)>>(),
+        vec![("a,b,,c,".to_string(), 0)]
+    );
+    assert_eq!(
+        "a,b,,c,"
+            .split_every_n_times_ranges(",", 0)
+            .collect::<Vec<Range<usize>>>(),
+        vec![0..7]
+    );
+    assert_eq!(
+        "a,b,,c,".split_every_n_times(",", 0).size_hint(),
+        (1, Some(1))
+    );
+    assert_eq!("a,b,,c,".split_every_n_times(",", 0).occurrences_left(), 4);
+    assert_eq!("".split_every_n_times(",", 0).next(), None);
+
+    let input: Vec<u8> = vec![1, 0, 2, 0, 0, 3, 0];
+    let whole: Vec<Vec<u8>> = vec![input.clone()];
+    assert_eq!(
+        input
+            .clone()
+            .split_every_n_times(vec![0], 0)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(
+        input
+            .as_slice()
+            .split_every_n_times(&[0], 0)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(
+        input
+            .as_slice()
+            .split_every_n_times_ref(&[0], 0)
+            .collect::<Vec<&[u8]>>(),
+        whole
+    );
+    assert_eq!(
+        VecDeque::from(input.clone())
+            .split_every_n_times(0, 0)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(
+        input
+            .clone()
+            .into_iter()
+            .split_every_n_times(0, 0)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(
+        input
+            .clone()
+            .into_iter()
+            .split_every_n_times(0, 0)
+            .with_max_splits(3)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(
+        input
+            .clone()
+            .into_iter()
+            .split_every_n_times_inclusive(0, 0)
+            .collect::<Vec<Vec<u8>>>(),
+        whole
+    );
+    assert_eq!(Vec::<u8>::new().split_every_n_times(0, 0).next(), None);
+    assert_eq!(
+        Vec::<u8>::new()
+            .into_iter()
+            .split_every_n_times(0, 0)
+            .next(),
+        None
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rsplit() {
+    let input: &str = "a b c d e";
+    let forward: Vec<String> = input.split_every_n_times(" ", 2).collect();
+    assert_eq!(forward, vec!["a b", "c d", "e"]);
+    let reversed: Vec<String> = input.split_every_n_times(" ", 2).rev().collect();
+    assert_eq!(reversed, vec!["e", "c d", "a b"]);
+    let mut splitter: RSplitEvery<&str, &str> = input.rsplit_every_n_times(" ", 2);
+    assert_eq!(splitter.size_hint(), (1, Some(5)));
+    assert_eq!(splitter.next().unwrap(), "d e");
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"RSplitEvery { input: "a b c d e", pat: " ", n: 2, ind: 0, .. }"#
+    );
+    assert_eq!(splitter.next().unwrap(), "b c");
+    assert_eq!(splitter.next().unwrap(), "a");
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next(), None);
+
+    // Mirrors the forward splitter's handling of empty chunks at either end.
+    assert_eq!(
+        " a b".split_every_n_times(" ", 1).collect::<Vec<String>>(),
+        vec!["", "a", "b"]
+    );
+    assert_eq!(
+        "a b ".rsplit_every_n_times(" ", 1).collect::<Vec<String>>(),
+        vec!["", "b", "a"]
+    );
+    assert_eq!(
+        " a b".rsplit_every_n_times(" ", 1).collect::<Vec<String>>(),
+        vec!["b", "a"]
+    );
+    assert_eq!(
+        "a b c"
+            .rsplit_every_n_times(" ", 0)
+            .collect::<Vec<String>>(),
+        vec!["a b c"]
+    );
+    assert_eq!("".rsplit_every_n_times(" ", 1).next(), None);
+
+    let input: [u8; 8] = [1, 0, 0, 2, 0, 3, 0, 4];
+    assert_eq!(
+        input
+            .as_slice()
+            .split_every_n_times(&[0], 3)
+            .collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 0, 0, 2], vec![3, 0, 4]]
+    );
+    assert_eq!(
+        input
+            .as_slice()
+            .rsplit_every_n_times(&[0], 3)
+            .collect::<Vec<Vec<u8>>>(),
+        vec![vec![2, 0, 3, 0, 4], vec![1, 0]]
+    );
+    assert_eq!(
+        input
+            .as_slice()
+            .rsplit_every_n_times([0, 2], 1)
+            .collect::<Vec<Vec<u8>>>(),
+        vec![vec![0, 3, 0, 4], vec![1, 0]]
+    );
+
+    // Searching from the end finds the same occurrences as the default `rfind_in`, which
+    // searches from the front, including for patterns that overlap themselves.
+    #[derive(Debug)]
+    struct Forward<'a>(&'a str);
+
+    impl StrPattern for Forward<'_> {
+        fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+            self.0.find_in(haystack)
+        }
+    }
+
+    let alphabet: [&str; 4] = ["a", "b", ",", "é"];
+    let mut inputs: Vec<String> = vec![String::new()];
+    for len in 0..6 {
+        for ind in 0..alphabet.len().pow(len) {
+            let mut input: String = String::new();
+            let mut ind: usize = ind;
+            for _ in 0..len {
+                input.push_str(alphabet[ind % alphabet.len()]);
+                ind /= alphabet.len();
+            }
+            inputs.push(input);
+        }
+    }
+    for input in &inputs {
+        for pat in [",", "aa", "aba", "ab", "é", "éa"] {
+            for n in 0..4 {
+                assert_eq!(
+                    input
+                        .as_str()
+                        .rsplit_every_n_times(pat, n)
+                        .collect::<Vec<String>>(),
+                    input
+                        .as_str()
+                        .rsplit_every_n_times(Forward(pat), n)
+                        .collect::<Vec<String>>(),
+                    "{input:?} {pat:?} {n}"
+                );
+                let bytes: &[u8] = input.as_bytes();
+                assert_eq!(
+                    bytes
+                        .rsplit_every_n_times(pat.as_bytes(), n)
+                        .collect::<Vec<Vec<u8>>>(),
+                    bytes
+                        .rsplit_every_n_times(ByEq(pat.as_bytes(), u8::eq), n)
+                        .collect::<Vec<Vec<u8>>>(),
+                    "{input:?} {pat:?} {n}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_utf8() {
+    let input: Vec<u8> = b"ok\nbad \xff\xfe\n\xe2\x82\xac".to_vec();
+    let mut splitter: SplitEveryUtf8<&[u8], u8> =
+        input.as_slice().split_every_n_times_utf8(b'\n', 1);
+    assert_eq!(splitter.next().unwrap().unwrap(), "ok");
+    let err: Utf8Error = splitter.next().unwrap().unwrap_err();
+    assert_eq!(err.valid_up_to(), 4);
+    assert_eq!(splitter.next().unwrap().unwrap(), "€");
+    assert!(splitter.next().is_none());
+
+    // A multi-byte character cut in half by the pattern is reported too.
+    let chunks: Vec<Result<String, Utf8Error>> = input
+        .into_iter()
+        .filter(|&byte| byte != b'\n')
+        .collect::<Vec<u8>>()
+        .split_every_n_times_utf8(0x82, 1)
+        .collect();
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks.iter().all(Result::is_err));
+}
+
+#[test]
+fn test_chunk_capacity() {
+    let mut splitter: SplitEvery<Box<dyn FnMut() -> Option<u32>>, u32> = (0..10)
+        .map(|val| val % 5)
+        .split_every_n_times(0, 1)
+        .chunk_capacity(64);
+    assert_eq!(splitter.next().unwrap(), Vec::<u32>::new());
+    let chunk: Vec<u32> = splitter.next().unwrap();
+    assert_eq!(chunk, [1, 2, 3, 4]);
+    assert!(chunk.capacity() >= 64);
+
+    let mut iter = (0..100).map(|val| val % 50);
+    let input: Box<dyn FnMut() -> Option<u32>> = Box::new(move || iter.next());
+    let chunk: Vec<u32> = SplitEvery::builder(input)
+        .pattern(49)
+        .chunk_capacity(49)
+        .build()
+        .next()
+        .unwrap();
+    assert_eq!(chunk.len(), 49);
+    assert_eq!(chunk.capacity(), 49);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_bytes() {
+    let input: bytes::Bytes = bytes::Bytes::from_static(b"GET\r\nHost\r\n\r\nbody");
+    let mut splitter: SplitEvery<bytes::Bytes, &[u8]> =
+        input.clone().split_every_n_times(b"\r\n".as_slice(), 1);
+    let chunk: bytes::Bytes = splitter.next().unwrap();
+    assert_eq!(chunk, "GET");
+    // The chunk points into the source buffer rather than a copy of it.
+    assert_eq!(chunk.as_ptr(), input.as_ptr());
+    assert_eq!(splitter.remainder(), "Host\r\n\r\nbody");
+    assert_eq!(splitter.remainder().as_ptr(), input[5..].as_ptr());
+    let rest: Vec<bytes::Bytes> = splitter.collect();
+    assert_eq!(rest, ["Host", "", "body"]);
+    assert_eq!(rest[2].as_ptr(), input[13..].as_ptr());
+
+    let input: bytes::Bytes = bytes::Bytes::from(Vec::from([1, 0, 2, 0, 3]));
+    let chunks: Vec<bytes::Bytes> = input.clone().split_every_n_times(0, 2).collect();
+    assert_eq!(chunks, [&[1, 0, 2][..], &[3][..]]);
+    assert_eq!(chunks[1].as_ptr(), input[4..].as_ptr());
+}
+
+#[test]
+fn test_lossless() {
+    for input in ["a,b,c", "a,b,", ",a,,b", ",,,", ""] {
+        for n in 0..4 {
+            let chunks: Vec<String> = input.split_every_n_times(",", n).lossless(true).collect();
+            assert_eq!(chunks.concat(), input);
+            assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+            let mut chunks: Vec<&str> = input
+                .split_every_n_times_ref(",", n)
+                .lossless(true)
+                .rev()
+                .collect();
+            chunks.reverse();
+            assert_eq!(chunks.concat(), input);
+
+            let bytes: Vec<u8> = input.bytes().collect();
+            let chunks: Vec<Vec<u8>> = bytes
+                .clone()
+                .split_every_n_times(b',', n)
+                .lossless(true)
+                .collect();
+            assert_eq!(chunks.concat(), bytes);
+            let chunks: Vec<Vec<u8>> = bytes
+                .clone()
+                .into_iter()
+                .split_every_n_times(b',', n)
+                .lossless(true)
+                .collect();
+            assert_eq!(chunks.concat(), bytes);
+        }
+    }
+    let chunks: Vec<String> = "a,b,c".split_every_n_times(",", 1).lossless(true).collect();
+    assert_eq!(chunks, ["a,", "b,", "c"]);
+
+    // Turning `lossless` off must not clobber inclusive mode set beforehand.
+    let chunks: Vec<String> = "a,b,c"
+        .split_every_n_times_inclusive(",", 1)
+        .lossless(false)
+        .collect();
+    assert_eq!(chunks, ["a,", "b,", "c"]);
+    let splitter: SplitEvery<&str, &str> = SplitEvery::builder("a,b,c")
+        .pattern(",")
+        .inclusive(true)
+        .lossless(false)
+        .build();
+    assert_eq!(splitter.collect::<Vec<String>>(), ["a,", "b,", "c"]);
+    let spec: SplitEverySpec<&str> = SplitEverySpec::builder()
+        .pattern(",")
+        .every(2)
+        .inclusive(true)
+        .lossless(false)
+        .spec();
+    assert_eq!(spec.apply("a,b,c").collect::<Vec<String>>(), ["a,b,", "c"]);
+    let chunks: Vec<String> = "a,b,c"
+        .split_every_n_times(",", 1)
+        .lossless(true)
+        .lossless(false)
+        .collect();
+    assert_eq!(chunks, ["a", "b", "c"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_os_str() {
+    let path: OsString = OsString::from("/usr/local/bin:/usr/bin:/bin:/opt/ünïcode/bin");
+    let mut splitter: SplitEvery<OsString, &OsStr> =
+        path.clone().split_every_n_times(OsStr::new(":"), 2);
+    assert_eq!(splitter.size_hint(), (1, Some(24)));
+    assert_eq!(splitter.next().unwrap(), "/usr/local/bin:/usr/bin");
+    assert_eq!(
+        format!("{splitter:?}"),
+        r#"SplitEvery { input: "/usr/local/bin:/usr/bin:/bin:/opt/ünïcode/bin", pat: ":", n: 2, ind: 24, .. }"#
+    );
+    assert_eq!(splitter.next().unwrap(), "/bin:/opt/ünïcode/bin");
+    assert_eq!(splitter.next(), None);
+
+    let dirs: Vec<OsString> = path.as_os_str().split_every_n_times(":", 1).collect();
+    assert_eq!(dirs, std::env::split_paths(&path).collect::<Vec<_>>());
+    let dirs: Vec<OsString> = path
+        .as_os_str()
+        .split_every_n_times(OsString::from("ï"), 1)
+        .collect();
+    assert_eq!(dirs, ["/usr/local/bin:/usr/bin:/bin:/opt/ün", "code/bin"]);
+    assert_eq!(
+        path.as_os_str()
+            .split_every_n_times("", 1)
+            .collect::<Vec<_>>(),
+        [path]
+    );
+}
+
+#[cfg(all(unix, feature = "std"))]
+#[test]
+fn test_os_str_non_utf8() {
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    let path: OsString = OsString::from_vec(b"a\xff:b:\xfe\xff:c".to_vec());
+    let dirs: Vec<OsString> = path.as_os_str().split_every_n_times(":", 2).collect();
+    assert_eq!(
+        dirs,
+        [
+            OsStr::from_bytes(b"a\xff:b"),
+            OsStr::from_bytes(b"\xfe\xff:c")
+        ]
+    );
+    // A pattern that is not valid UTF-8 never matches.
+    let dirs: Vec<OsString> = path
+        .as_os_str()
+        .split_every_n_times(OsStr::from_bytes(b"\xff"), 1)
+        .collect();
+    assert_eq!(dirs, [path]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_join_with() {
+    let input: &str = "Oh hi there I don't really know what to say";
+    assert_eq!(
+        input.split_every_n_times(" ", 3).join_with("\n"),
+        "Oh hi there\nI don't really\nknow what to\nsay"
+    );
+    let mut splitter: SplitEvery<String, &str> = input.to_string().split_every_n_times(" ", 4);
+    assert_eq!(splitter.next().unwrap(), "Oh hi there I");
+    assert_eq!(splitter.join_with(" | "), "don't really know what | to say");
+    assert_eq!("".split_every_n_times(" ", 1).join_with(","), "");
+    assert_eq!("a  b".split_every_n_times(" ", 1).join_with(","), "a,,b");
+}
+
+#[test]
+fn test_words_wrapped() {
+    let input: &str =
+        "  The quick brown fox jumps over the\tlazy dog, extraordinarily nimble and quick. ";
+    let lines: Vec<String> = input.split_every_n_words_wrapped(3, 16).collect();
+    assert_eq!(
+        lines,
+        [
+            "The quick brown",
+            "fox jumps over",
+            "the\tlazy dog,",
+            "extraordinarily",
+            "nimble and",
+            "quick."
+        ]
+    );
+    assert!(lines.iter().all(|line| line.chars().count() <= 16));
+
+    // Width wins over the word count, and a word wider than `max_width` stands alone.
+    let lines: Vec<String> = input.split_every_n_words_wrapped(0, 10).collect();
+    assert_eq!(
+        lines,
+        [
+            "The quick",
+            "brown fox",
+            "jumps over",
+            "the\tlazy",
+            "dog,",
+            "extraordinarily",
+            "nimble and",
+            "quick."
+        ]
+    );
+    let lines: Vec<String> = "héllo wörld ünïcode"
+        .split_every_n_words_wrapped(5, 11)
+        .collect();
+    assert_eq!(lines, ["héllo wörld", "ünïcode"]);
+    let splitter: SplitEveryWrapped<&str> = " \n ".split_every_n_words_wrapped(2, 80);
+    assert_eq!(splitter.count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_next_into() {
+    let mut splitter: SplitEvery<&str, &str> = "a b c d e".split_every_n_times(" ", 2);
+    let mut buf: String = String::with_capacity(16);
+    let ptr: *const u8 = buf.as_ptr();
+    for expected in ["a b", "c d", "e"] {
+        assert!(splitter.next_into(&mut buf));
+        assert_eq!(buf, expected);
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+    assert!(!splitter.next_into(&mut buf));
+    assert!(buf.is_empty());
+
+    let mut splitter: SplitEvery<String, char> = "x,y".to_string().split_every_n_times(',', 1);
+    assert!(splitter.next_into(&mut buf));
+    assert_eq!(buf, "x");
+
+    let mut splitter: SplitEvery<Vec<u8>, u8> = vec![1, 0, 2, 3, 0, 0, 4].split_every_n_times(0, 1);
+    let mut buf: Vec<u8> = Vec::with_capacity(16);
+    let ptr: *const u8 = buf.as_ptr();
+    for expected in [&[1][..], &[2, 3], &[], &[4]] {
+        assert!(splitter.next_into(&mut buf));
+        assert_eq!(buf, expected);
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+    assert!(!splitter.next_into(&mut buf));
+    let mut splitter: SplitEvery<&[u8], u8> = [5, 0, 6].as_slice().split_every_n_times(0, 1);
+    assert!(splitter.next_into(&mut buf));
+    assert_eq!(buf, [5]);
+    let mut splitter: SplitEvery<[u8; 3], u8> = [7, 0, 8].split_every_n_times(0, 1);
+    assert!(splitter.next_into(&mut buf));
+    assert!(splitter.next_into(&mut buf));
+    assert_eq!(buf, [8]);
+}
+
+#[test]
+fn test_slice_like_pattern() {
+    use alloc::vec;
+
+    /// Stands in for an inline small-vector type.
+    struct Inline {
+        buf: [u8; 4],
+        len: usize,
+    }
+
+    impl AsRef<[u8]> for Inline {
+        fn as_ref(&self) -> &[u8] {
+            &self.buf[..self.len]
+        }
+    }
+
+    let input: &[u8] = &[1, 0, 2, 2, 0, 3, 0, 2, 4, 0, 2, 5];
+    let expected: Vec<Vec<u8>> = vec![vec![1], vec![2, 0, 3], vec![4], vec![5]];
+    assert_eq!(
+        input
+            .split_every_n_times([0, 2], 1)
+            .collect::<Vec<Vec<u8>>>(),
+        expected
+    );
+    assert_eq!(
+        input
+            .split_every_n_times(Vec::from([0, 2]), 1)
+            .collect::<Vec<Vec<u8>>>(),
+        expected
+    );
+    assert_eq!(
+        input
+            .split_every_n_times(&Vec::from([0, 2]), 1)
+            .collect::<Vec<Vec<u8>>>(),
+        expected
+    );
+    let boxed: Box<[u8]> = Box::new([0, 2]);
+    assert_eq!(
+        input
+            .split_every_n_times(&boxed, 1)
+            .collect::<Vec<Vec<u8>>>(),
+        expected
+    );
+    let inline: Inline = Inline {
+        buf: [0, 2, 0, 0],
+        len: 2,
+    };
+    assert_eq!(
+        input
+            .split_every_n_times(&inline, 1)
+            .collect::<Vec<Vec<u8>>>(),
+        expected
+    );
+    assert_eq!(
+        input.to_vec().split_every_n_times_ref(&inline, 2).count(),
+        2
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_chunks_and_positions() {
+    let input: &str = "héllo wörld, how are you, fine";
+    let pairs: Vec<(Range<usize>, String)> = input
+        .split_every_n_times(", ", 1)
+        .chunks_and_positions()
+        .collect();
+    assert_eq!(
+        pairs,
+        [
+            (0..13, "héllo wörld".to_string()),
+            (15..26, "how are you".to_string()),
+            (28..32, "fine".to_string())
+        ]
+    );
+    let mut end: usize = 0;
+    for (range, chunk) in &pairs {
+        assert!(end <= range.start);
+        assert_eq!(&input[range.clone()], chunk);
+        end = range.end;
+    }
+
+    let input: Vec<u8> = vec![1, 0, 2, 0, 0, 3];
+    let splitter: SplitEveryPositions<Vec<u8>, u8> = input
+        .clone()
+        .split_every_n_times(0, 1)
+        .chunks_and_positions();
+    assert!(format!("{splitter:?}").starts_with("SplitEveryPositions {"));
+    let pairs: Vec<(Range<usize>, Vec<u8>)> = splitter.collect();
+    assert_eq!(
+        pairs,
+        [
+            (0..1, vec![1]),
+            (2..3, vec![2]),
+            (4..4, vec![]),
+            (5..6, vec![3])
+        ]
+    );
+    for (range, chunk) in pairs {
+        assert_eq!(input[range], chunk);
+    }
+    let lossless: Vec<u8> = input
+        .as_slice()
+        .split_every_n_times(0, 2)
+        .lossless(true)
+        .chunks_and_positions()
+        .flat_map(|(range, _)| input[range].to_vec())
+        .collect();
+    assert_eq!(lossless, input);
+}
+
+#[test]
+fn test_try() {
+    use alloc::vec;
+
+    let input: [Result<u8, &str>; 8] =
+        [Ok(1), Ok(0), Ok(2), Ok(3), Ok(0), Ok(4), Err("bad"), Ok(5)];
+    let mut splitter: SplitEveryTry<_, u8> = input.into_iter().try_split_every_n_times(0, 1);
+    assert_eq!(splitter.next(), Some(Ok(vec![1])));
+    assert_eq!(splitter.next(), Some(Ok(vec![2, 3])));
+    // The error replaces the chunk it interrupted.
+    assert_eq!(splitter.next(), Some(Err("bad")));
+    assert_eq!(splitter.next(), None);
+    assert_eq!(splitter.next(), None);
+    assert_eq!(
+        input
+            .into_iter()
+            .try_split_every_n_times(0, 1)
+            .collect::<Result<Vec<Vec<u8>>, &str>>(),
+        Err("bad")
+    );
+
+    let input: [Result<u8, &str>; 3] = [Ok(1), Ok(0), Err("bad")];
+    let chunks: Vec<Result<Vec<u8>, &str>> =
+        input.into_iter().try_split_every_n_times(0, 1).collect();
+    assert_eq!(chunks, [Ok(vec![1]), Err("bad")]);
+    let chunks: Vec<Result<Vec<u8>, &str>> = [Ok(1), Ok(0), Ok(2)]
+        .into_iter()
+        .try_split_every_n_times(0, 1)
+        .collect();
+    assert_eq!(chunks, [Ok(vec![1]), Ok(vec![2])]);
+}
+
+#[test]
+fn test_fold() {
+    let input: &str = "a,bb,,ccc,dddd";
+    let lens: Vec<usize> = input
+        .split_every_n_times(",", 1)
+        .fold(Vec::new(), |mut lens, chunk| {
+            lens.push(chunk.len());
+            lens
+        });
+    assert_eq!(lens, [1, 2, 0, 3, 4]);
+    let mut splitter: SplitEvery<&str, &str> = input.split_every_n_times(",", 1);
+    splitter.next();
+    assert_eq!(splitter.fold(0, |total, chunk| total + chunk.len()), 9);
+    assert_eq!(
+        input
+            .split_every_n_times(",", 2)
+            .map_chunks(str::len)
+            .fold(0, usize::max),
+        4
+    );
+
+    let input: &[u8] = &[1, 0, 2, 3, 0, 0, 4];
+    assert_eq!(
+        input
+            .split_every_n_times(0, 1)
+            .fold(0, |total, chunk| total + chunk.len()),
+        4
+    );
+    assert_eq!(
+        input
+            .split_every_n_times(0, 1)
+            .map_chunks(<[u8]>::len)
+            .fold(0, usize::max),
+        2
+    );
+}
+
+#[test]
+fn test_interior_delimiters() {
+    use alloc::vec;
+
+    let text: &str = "a,b,c,d,e,f,g,";
+    let expected: [&str; 3] = ["a,b,c", "d,e,f", "g,"];
+    let bytes: Vec<u8> = text.bytes().collect();
+    let to_bytes = |chunks: &[&str]| -> Vec<Vec<u8>> {
+        chunks.iter().map(|chunk| chunk.bytes().collect()).collect()
+    };
+
+    assert_eq!(
+        text.split_every_n_times(",", 3).collect::<Vec<String>>(),
+        expected
+    );
+    assert_eq!(
+        text.split_every_n_times_ref(",", 3).collect::<Vec<&str>>(),
+        expected
+    );
+    assert_eq!(
+        bytes
+            .clone()
+            .split_every_n_times(b',', 3)
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+    assert_eq!(
+        bytes
+            .as_slice()
+            .split_every_n_times(b',', 3)
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+    assert_eq!(
+        bytes
+            .clone()
+            .into_iter()
+            .split_every_n_times(b',', 3)
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+
+    let expected: [&str; 3] = ["abc", "def", "g"];
+    assert_eq!(
+        text.split_every_n_times(",", 3)
+            .drop_interior_delimiters()
+            .collect::<Vec<String>>(),
+        expected
+    );
+    assert_eq!(
+        bytes
+            .clone()
+            .split_every_n_times(b',', 3)
+            .drop_interior_delimiters()
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+    assert_eq!(
+        bytes
+            .as_slice()
+            .split_every_n_times(&b","[..], 3)
+            .drop_interior_delimiters()
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+    assert_eq!(
+        bytes
+            .into_iter()
+            .split_every_n_times(b',', 3)
+            .drop_interior_delimiters()
+            .collect::<Vec<Vec<u8>>>(),
+        to_bytes(&expected)
+    );
+    assert_eq!(
+        [1, 0, 2, 0, 0, 3]
+            .split_every_n_times(0, 2)
+            .drop_interior_delimiters()
+            .collect::<Vec<Vec<u8>>>(),
+        vec![vec![1, 2], vec![3]]
+    );
+}
+
+#[test]
+fn test_iter_of_refs() {
+    struct Big(String);
+
+    impl Clone for Big {
+        fn clone(&self) -> Self {
+            panic!("cloned {}", self.0)
+        }
+    }
+
+    impl PartialEq for Big {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    let items: Vec<Big> = ["a", "|", "b", "|", "c"]
+        .into_iter()
+        .map(|item| Big(String::from(item)))
+        .collect();
+    let sep: Big = Big(String::from("|"));
+    let chunks: Vec<Vec<&Big>> = items.iter().split_every_n_times(&sep, 1).collect();
+    assert_eq!(chunks.len(), 3);
+    assert!(core::ptr::eq(chunks[0][0], &items[0]));
+    assert!(core::ptr::eq(chunks[2][0], &items[4]));
+    let chunks: Vec<Vec<&Big>> = items.iter().split_every_n_times_iter(&sep, 2).collect();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].len(), 3);
+}
+
+#[test]
+fn test_backend_consistency() {
+    struct Options {
+        lossless: bool,
+        skip_empty: bool,
+        overlapping: bool,
+        max_splits: Option<usize>,
+    }
+
+    fn configure<Input, Pattern>(
+        split_every: SplitEvery<Input, Pattern>,
+        options: &Options,
+    ) -> SplitEvery<Input, Pattern> {
+        let split_every: SplitEvery<Input, Pattern> = split_every
+            .lossless(options.lossless)
+            .skip_empty(options.skip_empty)
+            .overlapping(options.overlapping);
+        match options.max_splits {
+            Some(max_splits) => split_every.with_max_splits(max_splits),
+            None => split_every,
+        }
+    }
+
+    let mut configs: Vec<Options> = Vec::new();
+    for (lossless, skip_empty, overlapping) in [
+        (false, false, false),
+        (true, false, false),
+        (false, true, false),
+        (false, false, true),
+        (false, true, true),
+    ] {
+        for max_splits in [None, Some(0), Some(2)] {
+            configs.push(Options {
+                lossless,
+                skip_empty,
+                overlapping,
+                max_splits,
+            });
+        }
+    }
+    let alphabet: [char; 3] = ['a', ',', 'é'];
+    for len in 0..=6u32 {
+        for mut code in 0..alphabet.len().pow(len) {
+            let mut text: String = String::new();
+            for _ in 0..len {
+                text.push(alphabet[code % alphabet.len()]);
+                code /= alphabet.len();
+            }
+            let chars: Vec<char> = text.chars().collect();
+            for n in 0..4 {
+                for options in &configs {
+                    for pat in [",", "é", "aa", ",é"] {
+                        let context = format_args!(
+                            "{text:?} {pat:?} n={n} lossless={} skip_empty={} \
+                             overlapping={} max_splits={:?}",
+                            options.lossless,
+                            options.skip_empty,
+                            options.overlapping,
+                            options.max_splits
+                        );
+                        let pat_chars: Vec<char> = pat.chars().collect();
+                        let expected: Vec<Vec<char>> =
+                            configure(SplitEvery::new(&*text, pat, n), options)
+                                .map(|chunk| chunk.chars().collect())
+                                .collect();
+                        let from_vec: Vec<Vec<char>> = configure(
+                            SplitEvery::new(chars.clone(), pat_chars.clone(), n),
+                            options,
+                        )
+                        .collect();
+                        assert_eq!(from_vec, expected, "{context}");
+                        let from_slice: Vec<Vec<char>> =
+                            configure(SplitEvery::new(&*chars, &*pat_chars, n), options).collect();
+                        assert_eq!(from_slice, expected, "{context}");
+                        if let [pat] = *pat_chars {
+                            let mut iter = chars.iter().copied();
+                            let from_fn: Vec<Vec<char>> = configure(
+                                SplitEvery::n_times_from_fn(move || iter.next(), pat, n),
+                                options,
+                            )
+                            .collect();
+                            assert_eq!(from_fn, expected, "{context}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_group_every() {
+    use alloc::vec;
+
+    assert_eq!("a,b,c,d,e".group_every(",", 2), ["a,b", "c,d", "e"]);
+    assert!("".group_every(",", 2).is_empty());
+    assert_eq!(
+        [1u8, 0, 2, 0, 3].group_every(0, 1),
+        vec![vec![1], vec![2], vec![3]]
+    );
+    assert_eq!(
+        vec![1u8, 0, 2, 0, 3].as_slice().group_every(0, 2),
+        vec![vec![1, 0, 2], vec![3]]
+    );
+}
+
+#[test]
+fn test_split_with_break() {
+    use SplitEveryTrigger::{Break, Count, End};
+
+    let chunks: Vec<(String, SplitEveryTrigger)> = "a,b,c;d,e;f,g,h,i"
+        .split_every_n_with_break(",", 2, ";")
+        .collect();
+    let chunks: Vec<(&str, SplitEveryTrigger)> = chunks
+        .iter()
+        .map(|(chunk, trigger)| (chunk.as_str(), *trigger))
+        .collect();
+    assert_eq!(
+        chunks,
+        [
+            ("a,b", Count),
+            ("c", Break),
+            ("d,e", Break),
+            ("f,g", Count),
+            ("h,i", End)
+        ]
+    );
+
+    let triggers: Vec<SplitEveryTrigger> = ";a,;b,,c"
+        .split_every_n_with_break(",", 1, ";")
+        .map(|(_, trigger)| trigger)
+        .collect();
+    assert_eq!(triggers, [Break, Count, Break, Count, Count, End]);
+
+    let chunks: Vec<(String, SplitEveryTrigger)> =
+        "a,b;c,d".split_every_n_with_break(",", 0, ";").collect();
+    assert_eq!(chunks[0], (String::from("a,b"), Break));
+    assert_eq!(chunks[1], (String::from("c,d"), End));
+
+    let chunks: Vec<(String, SplitEveryTrigger)> =
+        "a;;b;".split_every_n_with_break(";;", 1, ";").collect();
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks.iter().all(|(_, trigger)| *trigger == Break));
+    assert_eq!("".split_every_n_with_break(",", 1, ";").next(), None);
+}
+
+#[test]
+fn test_split_every_n_chars() {
+    let chunks: Vec<String> = "hello world foo"
+        .chars()
+        .split_every_n_chars(' ', 1)
+        .collect();
+    assert_eq!(chunks, ["hello", "world", "foo"]);
+    let chunks: Vec<String> = "hello world foo"
+        .chars()
+        .split_every_n_chars(' ', 2)
+        .collect();
+    assert_eq!(chunks, ["hello world", "foo"]);
+    assert_eq!("".chars().split_every_n_chars(' ', 1).next(), None);
+}
+
+#[test]
+fn test_spec() {
+    let spec: SplitEverySpec<&str> = SplitEverySpec::new(",", 2);
+    assert_eq!(
+        spec.apply("a,b,c,d,e").collect::<Vec<String>>(),
+        ["a,b", "c,d", "e"]
+    );
+    assert_eq!(spec.apply("x,y,z").collect::<Vec<String>>(), ["x,y", "z"]);
+
+    let spec: SplitEverySpec<u8> = SplitEverySpec::builder()
+        .pattern(0)
+        .skip_empty(true)
+        .max_splits(2)
+        .spec();
+    assert_eq!(
+        spec.apply([1, 0, 0, 2, 0, 3]).collect::<Vec<Vec<u8>>>(),
+        [Vec::from([1]), Vec::from([0, 2, 0, 3])]
+    );
+    assert_eq!(
+        spec.apply([0, 4]).collect::<Vec<Vec<u8>>>(),
+        [Vec::from([4])]
+    );
+}
+
+#[test]
+fn test_scan_offsets() {
+    for (input, n) in [
+        ("a,b,,c,d,", 1),
+        ("a,b,,c,d,", 2),
+        (",,", 3),
+        ("", 1),
+        ("abc", 0),
+    ] {
+        let offsets: Vec<usize> = SplitEvery::scan_offsets(input, ",", n);
+        let chunks: Vec<String> = input.split_every_n_times(",", n).collect();
+        assert_eq!(offsets.len(), chunks.len());
+        for (offset, chunk) in offsets.iter().zip(&chunks) {
+            assert!(input[*offset..].starts_with(chunk.as_str()));
+        }
+    }
+
+    let input: [u8; 7] = [1, 0, 2, 0, 0, 3, 0];
+    let offsets: Vec<usize> = SplitEvery::scan_offsets(&input[..], 0, 2);
+    assert_eq!(offsets, [0, 4]);
+    let chunks: Vec<Vec<u8>> = input.as_slice().split_every_n_times(0, 2).collect();
+    assert_eq!(&input[offsets[0]..offsets[1] - 1], chunks[0]);
+    assert_eq!(&input[offsets[1]..input.len() - 1], chunks[1]);
+}
+
+#[test]
+fn test_fn_final_chunk() {
+    use alloc::vec;
+
+    let split = |source: Vec<u8>, n: usize| -> (Vec<Vec<u8>>, usize) {
+        let mut calls: usize = 0;
+        let mut iter = source.into_iter();
+        let chunks: Vec<Vec<u8>> = SplitEvery::n_times_from_fn(
+            || {
+                calls += 1;
+                iter.next()
+            },
+            0,
+            n,
+        )
+        .collect();
+        (chunks, calls)
+    };
+
+    // Ends right on a group boundary.
+    assert_eq!(split(vec![1, 0, 2, 0], 2), (vec![vec![1, 0, 2]], 5));
+    // Ends one pattern short of a boundary.
+    assert_eq!(
+        split(vec![1, 0, 2, 0, 3, 0], 2),
+        (vec![vec![1, 0, 2], vec![3, 0]], 7)
+    );
+    // Ends one value short of a boundary.
+    assert_eq!(
+        split(vec![1, 0, 2, 0, 3], 2),
+        (vec![vec![1, 0, 2], vec![3]], 6)
+    );
+    assert_eq!(
+        split(vec![1, 0, 2, 0, 3, 0, 4], 3),
+        (vec![vec![1, 0, 2, 0, 3], vec![4]], 8)
+    );
+    assert_eq!(split(vec![0, 0], 2), (vec![vec![0]], 3));
+    assert_eq!(split(vec![0], 2), (vec![vec![0]], 2));
+    assert_eq!(split(vec![], 2), (vec![], 1));
+
+    for (source, n) in [
+        (vec![1, 0, 2, 0], 2),
+        (vec![1, 0, 2, 0, 3, 0], 2),
+        (vec![0, 0], 2),
+    ] {
+        let text: String = source
+            .iter()
+            .map(|val| {
+                if *val == 0 {
+                    ','
+                } else {
+                    char::from(b'0' + val)
+                }
+            })
+            .collect();
+        let expected: Vec<Vec<u8>> = text
+            .split_every_n_times(",", n)
+            .map(|chunk| {
+                chunk
+                    .bytes()
+                    .map(|val| if val == b',' { 0 } else { val - b'0' })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(split(source, n).0, expected);
+    }
+}
+
+#[test]
+fn test_window_overlap() {
+    let windows: Vec<String> = "the quick brown fox jumps"
+        .split_every_n_times(" ", 2)
+        .window_overlap(1)
+        .collect();
+    assert_eq!(
+        windows,
+        ["the quick", "quick brown", "brown fox", "fox jumps"]
+    );
+    let windows: Vec<String> = "a b c d e f g"
+        .split_every_n_times(" ", 3)
+        .window_overlap(1)
+        .collect();
+    assert_eq!(windows, ["a b c", "c d e", "e f g"]);
+    let windows: Vec<String> = "a b c d e"
+        .split_every_n_times(" ", 3)
+        .window_overlap(2)
+        .collect();
+    assert_eq!(windows, ["a b c", "b c d", "c d e"]);
+    let windows: Vec<String> = "a b c"
+        .split_every_n_times(" ", 2)
+        .window_overlap(5)
+        .collect();
+    assert_eq!(windows, ["a b", "b c"]);
+    let windows: Vec<String> = "a b c"
+        .split_every_n_times(" ", 2)
+        .window_overlap(0)
+        .collect();
+    assert_eq!(windows, ["a b", "c"]);
+    let windows: Vec<String> = "a b "
+        .split_every_n_times(" ", 2)
+        .window_overlap(1)
+        .collect();
+    assert_eq!(windows, ["a b"]);
+    let windows: Vec<String> = "a b"
+        .split_every_n_times(" ", 0)
+        .window_overlap(1)
+        .collect();
+    assert_eq!(windows, ["a b"]);
+    assert_eq!(
+        "".split_every_n_times(" ", 2).window_overlap(1).next(),
+        None
     );
-    assert_eq!(splitter.next().unwrap(), vec!["them"]);
+
+    let input: [u8; 5] = [1, 0, 2, 0, 3];
+    let windows: Vec<Vec<u8>> = input
+        .as_slice()
+        .split_every_n_times(0, 2)
+        .window_overlap(1)
+        .collect();
+    assert_eq!(windows, [Vec::from([1, 0, 2]), Vec::from([2, 0, 3])]);
+}
+
+#[test]
+fn test_with_remainders() {
+    let pairs: Vec<(&str, &str)> = "a b c d e"
+        .split_every_n_times_ref(" ", 2)
+        .with_remainders()
+        .collect();
+    assert_eq!(pairs, [("a b", "c d e"), ("c d", "e"), ("e", "")]);
+    let mut pairs = "a,b,".split_every_n_times_ref(",", 1).with_remainders();
+    assert_eq!(pairs.next(), Some(("a", "b,")));
+    assert_eq!(pairs.next(), Some(("b", "")));
+    assert_eq!(pairs.next(), None);
+
+    let input: [u8; 5] = [1, 0, 2, 0, 3];
+    let pairs: Vec<(&[u8], &[u8])> = input
+        .as_slice()
+        .split_every_n_times_ref(0, 1)
+        .with_remainders()
+        .collect();
+    assert_eq!(
+        pairs,
+        [
+            (&[1][..], &[2, 0, 3][..]),
+            (&[2][..], &[3][..]),
+            (&[3][..], &[][..])
+        ]
+    );
+}
+
+#[test]
+fn test_wide_strings() {
+    let wide = |text: &str| -> Vec<u16> { text.encode_utf16().collect() };
+    let list: Vec<u16> = wide("C:\\bin\0D:\\tools\0\0");
+
+    let entries: Vec<Vec<u16>> = list
+        .as_slice()
+        .split_every_n_times(0u16, 1)
+        .take_while(|entry| !entry.is_empty())
+        .collect();
+    assert_eq!(entries, [wide("C:\\bin"), wide("D:\\tools")]);
+    let entries: Vec<&[u16]> = list
+        .as_slice()
+        .split_every_n_times_ref(0u16, 1)
+        .skip_empty(true)
+        .collect();
+    assert_eq!(entries, [wide("C:\\bin"), wide("D:\\tools")]);
+
+    let pairs: Vec<Vec<u16>> = wide("a\0b\0c\0d").split_every_n_times(0u16, 2).collect();
+    assert_eq!(pairs, [wide("a\0b"), wide("c\0d")]);
+    let pairs: Vec<Vec<u16>> = wide("a, b, c")
+        .split_every_n_times(&wide(", ")[..], 2)
+        .collect();
+    assert_eq!(pairs, [wide("a, b"), wide("c")]);
+}
+
+#[test]
+fn test_byte_set() {
+    let text: &str = "one two\tthree\nfour  five";
+    assert_eq!(
+        text.split_every_n_times_byte_set(b" \t\n", 2)
+            .collect::<Vec<String>>(),
+        ["one two", "three\nfour", " five"]
+    );
+    assert_eq!(
+        text.as_bytes()
+            .split_every_n_times_byte_set(b" \t\n", 1)
+            .skip_empty(true)
+            .collect::<Vec<Vec<u8>>>(),
+        [&b"one"[..], b"two", b"three", b"four", b"five"]
+    );
+
+    let set: ByteSet = ByteSet::new(b"\0\xff");
+    assert!(set.contains(0) && set.contains(255) && !set.contains(b'a'));
+    assert_eq!(
+        "a\u{ff}b"
+            .split_every_n_times_byte_set(&[0xc3, 0xbf], 1)
+            .count(),
+        1
+    );
+    assert_eq!(
+        [1u8, 0xff, 2]
+            .split_every_n_times(set, 1)
+            .collect::<Vec<Vec<u8>>>(),
+        [[1], [2]]
+    );
+}
+
+#[test]
+fn test_cursor_position() {
+    let mut splitter: SplitEvery<&str, &str> = "ab,cd,é,f".split_every_n_times(",", 1);
+    assert_eq!(splitter.byte_offset(), 0);
+    for expected in [3, 6, 9, 10] {
+        splitter.next();
+        assert_eq!(splitter.byte_offset(), expected);
+        assert_eq!(
+            splitter.byte_offset(),
+            "ab,cd,é,f".len() - splitter.remainder().len()
+        );
+    }
+    splitter.reset();
+    assert_eq!(splitter.byte_offset(), 0);
+
+    let mut splitter = "a,b,c,d,e".split_every_n_times_ref(",", 2);
+    assert_eq!(splitter.next_back(), Some("e"));
+    assert_eq!(splitter.byte_offset(), 0);
+    assert_eq!(splitter.next(), Some("a,b"));
+    assert_eq!(splitter.byte_offset(), 4);
+
+    let mut splitter: SplitEvery<&[u8], u8> =
+        [1, 0, 2, 2, 0, 3].as_slice().split_every_n_times(0, 1);
+    for expected in [2, 5, 6] {
+        splitter.next();
+        assert_eq!(splitter.index(), expected);
+    }
+    let mut splitter: SplitEvery<Vec<u8>, u8> = Vec::from([1, 0, 2]).split_every_n_times(0, 1);
+    splitter.next();
+    assert_eq!(splitter.index(), 2);
+}
+
+#[test]
+fn test_seek_to() {
+    let text: &str = "ab,cd,é,f,gh";
+    let mut splitter: SplitEvery<&str, &str> = text.split_every_n_times(",", 2);
+    splitter.next();
+    let checkpoint: usize = splitter.byte_offset();
+    let expected: Vec<String> = splitter.collect();
+
+    let mut resumed: SplitEvery<&str, &str> = text.split_every_n_times(",", 2);
+    resumed.seek_to(checkpoint);
+    assert_eq!(resumed.collect::<Vec<String>>(), expected);
+
+    let mut resumed = text.split_every_n_times_ref(",", 2);
+    resumed.by_ref().for_each(drop);
+    resumed.seek_to(checkpoint);
+    assert_eq!(resumed.next(), Some("é,f"));
+    resumed.seek_to(text.len());
+    assert_eq!(resumed.next(), None);
+
+    let input: [u8; 6] = [1, 0, 2, 0, 3, 0];
+    let mut splitter: SplitEvery<&[u8], u8> = input.as_slice().split_every_n_times(0, 1);
+    splitter.seek_to(2);
+    assert_eq!(splitter.collect::<Vec<Vec<u8>>>(), [[2], [3]]);
+}
+
+#[test]
+#[should_panic(expected = "not on a char boundary")]
+fn test_seek_to_char_boundary() {
+    "é,a".split_every_n_times(",", 1).seek_to(1);
+}
+
+#[test]
+#[should_panic(expected = "is outside the input")]
+fn test_position_outside_input() {
+    let mut splitter: SplitEvery<&str, &str> = "a,b".split_every_n_times(",", 1);
+    splitter.back = 4;
+    splitter.next();
+}
+
+#[test]
+#[should_panic(expected = "is not a char boundary")]
+fn test_position_inside_char() {
+    let mut splitter: SplitEvery<&str, &str> = "é,a".split_every_n_times(",", 1);
+    splitter.ind = 1;
+    splitter.next();
+}
+
+#[test]
+fn test_mut_slice() {
+    let mut buf: [u8; 9] = [1, 2, 0, 3, 0, 4, 5, 0, 6];
+    for chunk in buf.as_mut_slice().split_every_n_times_ref(0, 1) {
+        chunk[0] = 9;
+    }
+    assert_eq!(buf, [9, 2, 0, 9, 0, 9, 5, 0, 9]);
+
+    let mut buf: [u8; 9] = [1, 2, 0, 3, 0, 4, 5, 0, 6];
+    let chunks: Vec<&mut [u8]> = buf
+        .as_mut_slice()
+        .split_every_n_times_ref(&[0][..], 2)
+        .collect();
+    assert_eq!(chunks, [&mut [1, 2, 0, 3][..], &mut [4, 5, 0, 6][..]]);
+    for chunk in chunks {
+        chunk.reverse();
+    }
+    assert_eq!(buf, [3, 0, 2, 1, 0, 6, 0, 5, 4]);
+
+    let mut buf: [u8; 4] = [0, 0, 1, 0];
+    let chunks: Vec<&mut [u8]> = buf
+        .as_mut_slice()
+        .split_every_n_times_ref(0, 1)
+        .skip_empty(true)
+        .collect();
+    assert_eq!(chunks, [&mut [1][..]]);
+    let mut buf: [u8; 0] = [];
+    assert_eq!(
+        buf.as_mut_slice().split_every_n_times_ref(0, 1).next(),
+        None
+    );
+}
+
+#[test]
+fn test_single_occurrence_parity() {
+    for text in ["a,b,,c,é,,", ",a,,b", "é,é"] {
+        for pat in [",", ",,", "é"] {
+            let splitter: SplitEvery<&str, &str> =
+                SplitEvery::new(text, pat, 1).keep_trailing_empty(true);
+            assert_eq!(
+                splitter.clone().collect::<Vec<String>>(),
+                text.split(pat).collect::<Vec<&str>>(),
+                "{text:?} {pat:?}"
+            );
+            assert_eq!(
+                splitter.clone().lossless(true).collect::<Vec<String>>(),
+                text.split_inclusive(pat).collect::<Vec<&str>>(),
+                "{text:?} {pat:?}"
+            );
+            assert_eq!(
+                splitter.clone().skip_empty(true).collect::<Vec<String>>(),
+                text.split(pat)
+                    .filter(|chunk| !chunk.is_empty())
+                    .collect::<Vec<&str>>(),
+                "{text:?} {pat:?}"
+            );
+            assert_eq!(
+                splitter.clone().with_max_splits(2).collect::<Vec<String>>(),
+                text.splitn(2, pat).collect::<Vec<&str>>(),
+                "{text:?} {pat:?}"
+            );
+            assert_eq!(
+                splitter.rev().collect::<Vec<String>>(),
+                text.rsplit(pat).collect::<Vec<&str>>(),
+                "{text:?} {pat:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_owned_input_borrowed_pattern() {
+    const CRLF: &[u8] = b"\r\n";
+
+    let input: Vec<u8> = b"a\r\nb\r\nc\r\nd".to_vec();
+    let mut splitter: SplitEvery<Vec<u8>, &[u8]> = input.split_every_n_times(CRLF, 2);
+    assert_eq!(splitter.next(), Some(b"a\r\nb".to_vec()));
+    assert_eq!(splitter.remainder(), b"c\r\nd");
+    assert_eq!(splitter.next(), Some(b"c\r\nd".to_vec()));
     assert_eq!(splitter.next(), None);
 
-    #[allow(clippy::type_complexity)]
-    let mut splitter: SplitEvery<&[(u8, u8)], &[(u8, u8)]> = [
-        (0, 0),
-        (0, 1),
-        (0, 0), // Split
-        (0, 0),
-        (0, 0), // Split
-        (0, 1),
-        (0, 0),
-        (0, 0), // Split
-        (0, 1),
-    ]
-    .split_every_n_times(&[(0, 0)], 2);
-    assert_eq!(splitter.next().unwrap(), vec![(0, 0), (0, 1)]);
-    assert_eq!(splitter.next().unwrap(), vec![(0, 0)]);
-    assert_eq!(splitter.next().unwrap(), vec![(0, 1), (0, 0)]);
-    assert_eq!(splitter.next().unwrap(), vec![(0, 1)]);
+    let chunks: Vec<Vec<u8>> = b"a\r\nb".split_every_n_times(CRLF, 1).collect();
+    assert_eq!(chunks, [b"a".to_vec(), b"b".to_vec()]);
+}
+
+#[test]
+fn test_trim_chunks() {
+    let text: &str = " a , b , c ";
+    assert_eq!(
+        text.split_every_n_times(",", 2)
+            .trim_chunks(true)
+            .collect::<Vec<String>>(),
+        ["a , b", "c"]
+    );
+    assert_eq!(
+        text.split_every_n_times_ranges(",", 2)
+            .collect::<Vec<Range<usize>>>(),
+        [0..7, 8..11]
+    );
+    let mut splitter = text.split_every_n_times_ref(",", 1).trim_chunks(true);
+    assert_eq!(splitter.next_back(), Some("c"));
+    assert_eq!(splitter.next(), Some("a"));
+    assert_eq!(splitter.remainder(), " b ");
+    assert_eq!(splitter.next(), Some("b"));
     assert_eq!(splitter.next(), None);
 
-    let mut splitter: SplitEvery<&str, &str> =
-        "Oh hi there I don't really know what to say".split_every_n_times(" ", 3);
-    assert_eq!(splitter.next().unwrap(), "Oh hi there");
-    assert_eq!(splitter.next().unwrap(), "I don't really");
-    assert_eq!(splitter.next().unwrap(), "know what to");
-    assert_eq!(splitter.next().unwrap(), "say");
+    // Trimming never moves a split: "x" and "y" stay in separate chunks.
+    let splitter: SplitEvery<&str, &str> = SplitEvery::builder("\tx ||  || y\n")
+        .pattern("||")
+        .trim_chunks(true)
+        .build();
+    assert_eq!(splitter.collect::<Vec<String>>(), ["x", "", "y"]);
+    let splitter = "\tx ||  || y\n"
+        .split_every_n_times_ref("||", 1)
+        .trim_chunks(true)
+        .skip_empty(true);
+    assert_eq!(splitter.collect::<Vec<&str>>(), ["x", "y"]);
+}
+
+#[test]
+fn test_by_eq() {
+    let close = |val: &f64, pat: &f64| (val - pat).abs() < 1e-9;
+    let readings: Vec<f64> = Vec::from([1.5, 0.1 + 0.2, 2.5, 0.3, 3.5, 0.30000001]);
+    assert_eq!(
+        readings
+            .clone()
+            .split_every_n_times_by_eq([0.3], 1, close)
+            .collect::<Vec<Vec<f64>>>(),
+        [
+            Vec::from([1.5]),
+            Vec::from([2.5]),
+            Vec::from([3.5, 0.30000001])
+        ]
+    );
+    assert_eq!(
+        readings
+            .as_slice()
+            .split_every_n_times_by_eq([2.5, 0.3], 1, close)
+            .collect::<Vec<Vec<f64>>>(),
+        [Vec::from([1.5, 0.1 + 0.2]), Vec::from([3.5, 0.30000001])]
+    );
+
+    let words: [&str; 5] = ["Hello", "AND", "goodbye", "and", "hi"];
+    let chunks: Vec<&[&str]> = words
+        .as_slice()
+        .split_every_n_times_ref(
+            ByEq(["and"], |val: &&str, pat: &&str| {
+                val.eq_ignore_ascii_case(pat)
+            }),
+            1,
+        )
+        .collect();
+    assert_eq!(chunks, [&["Hello"][..], &["goodbye"], &["hi"]]);
+    assert_eq!(
+        readings
+            .as_slice()
+            .split_every_n_times_by_eq([], 1, close)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_cloneable_fn_reset() {
+    use alloc::vec;
+
+    let mut count: u32 = 0;
+    let counter = move || {
+        count += 1;
+        (count <= 7).then_some(count % 3)
+    };
+    let mut splitter = SplitEvery::n_times_from_cloneable_fn(counter, 0, 1);
+    let first: Vec<Vec<u32>> = splitter.by_ref().collect();
+    assert_eq!(first, [vec![1, 2], vec![1, 2], vec![1]]);
     assert_eq!(splitter.next(), None);
+    splitter.reset();
+    assert_eq!(splitter.collect::<Vec<Vec<u32>>>(), first);
+
+    let mut splitter = SplitEvery::n_times_from_cloneable_fn(counter, 0, 1);
+    assert_eq!(splitter.next(), Some(vec![1, 2]));
+    splitter.reset();
+    assert_eq!(splitter.collect::<Vec<Vec<u32>>>(), first);
+}
+
+#[test]
+fn test_trailing_pattern_consistency() {
+    use alloc::vec;
+
+    for text in ["a,b,", "a,b", ",", ",,", "a,,", ""] {
+        let chars: Vec<char> = text.chars().collect();
+        for n in 1..3 {
+            let expected: Vec<Vec<char>> = text
+                .split_every_n_times(",", n)
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            let from_vec: Vec<Vec<char>> =
+                chars.clone().split_every_n_times(vec![','], n).collect();
+            let from_slice: Vec<Vec<char>> =
+                chars.as_slice().split_every_n_times([','], n).collect();
+            let from_ref: Vec<Vec<char>> = chars
+                .as_slice()
+                .split_every_n_times_ref([','], n)
+                .map(<[char]>::to_vec)
+                .collect();
+            let from_str_ref: Vec<Vec<char>> = text
+                .split_every_n_times_ref(",", n)
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            assert_eq!(from_vec, expected, "{text:?} n={n}");
+            assert_eq!(from_slice, expected, "{text:?} n={n}");
+            assert_eq!(from_ref, expected, "{text:?} n={n}");
+            assert_eq!(from_str_ref, expected, "{text:?} n={n}");
+
+            let mut back_str: Vec<Vec<char>> = text
+                .split_every_n_times(",", n)
+                .rev()
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            back_str.reverse();
+            assert_eq!(back_str, expected, "{text:?} n={n}");
+        }
+    }
+    assert_eq!(
+        "a,b,".split_every_n_times(",", 1).collect::<Vec<String>>(),
+        ["a", "b"]
+    );
+    assert_eq!(
+        vec!['a', ',', 'b', ',']
+            .split_every_n_times(vec![','], 1)
+            .collect::<Vec<Vec<char>>>(),
+        [vec!['a'], vec!['b']]
+    );
+}
+
+#[test]
+fn test_keep_trailing_empty() {
+    for (text, kept, dropped) in [
+        ("a,b,", &["a", "b", ""][..], &["a", "b"][..]),
+        ("a,b", &["a", "b"], &["a", "b"]),
+        (",", &["", ""], &[""]),
+        ("", &[""], &[]),
+    ] {
+        let chars: Vec<char> = text.chars().collect();
+        for (keep, expected) in [(true, kept), (false, dropped)] {
+            let expected: Vec<Vec<char>> = expected
+                .iter()
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            let from_str: Vec<Vec<char>> = SplitEvery::new(text, ",", 1)
+                .keep_trailing_empty(keep)
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            let mut from_back: Vec<Vec<char>> = SplitEvery::new(text, ",", 1)
+                .keep_trailing_empty(keep)
+                .rev()
+                .map(|chunk| chunk.chars().collect())
+                .collect();
+            from_back.reverse();
+            let from_vec: Vec<Vec<char>> = SplitEvery::new(chars.clone(), [','], 1)
+                .keep_trailing_empty(keep)
+                .collect();
+            let mut iter = chars.iter().copied();
+            let from_fn: Vec<Vec<char>> = SplitEvery::n_times_from_fn(move || iter.next(), ',', 1)
+                .keep_trailing_empty(keep)
+                .collect();
+            assert_eq!(from_str, expected, "{text:?} keep={keep}");
+            assert_eq!(from_back, expected, "{text:?} keep={keep}");
+            assert_eq!(from_vec, expected, "{text:?} keep={keep}");
+            assert_eq!(from_fn, expected, "{text:?} keep={keep}");
+        }
+    }
+
+    assert_eq!(
+        "a,b,c,d,"
+            .split_every_n_times_keep_last(",", 2)
+            .collect::<Vec<String>>(),
+        ["a,b", "c,d", ""]
+    );
+    assert_eq!(
+        "a,b,c,"
+            .split_every_n_times_keep_last(",", 2)
+            .collect::<Vec<String>>(),
+        ["a,b", "c,"]
+    );
+    let splitter = SplitEvery::new("a,b,", ",", 1).keep_trailing_empty(true);
+    assert_eq!(splitter.size_hint().0, 1);
+    assert_eq!(
+        splitter.clone().lossless(true).collect::<Vec<String>>(),
+        ["a,", "b,"]
+    );
+    assert_eq!(
+        splitter.skip_empty(true).collect::<Vec<String>>(),
+        ["a", "b"]
+    );
+    assert_eq!(
+        [1, 0, 2, 0]
+            .as_slice()
+            .split_every_n_times_ref([0], 1)
+            .keep_trailing_empty(true)
+            .collect::<Vec<&[i32]>>(),
+        [&[1][..], &[2], &[]]
+    );
+}
+
+#[test]
+fn test_parse() {
+    use core::num::ParseIntError;
+
+    let nums: Result<Vec<i32>, ParseIntError> =
+        "1 2 3 4".split_every_n_times_parse(" ", 1).collect();
+    assert_eq!(nums, Ok(Vec::from([1, 2, 3, 4])));
+
+    let mut parsed = "1 -2 x 4".split_every_n_times_parse::<i32, _>(" ", 1);
+    assert_eq!(parsed.next(), Some(Ok(1)));
+    assert_eq!(parsed.next(), Some(Ok(-2)));
+    assert!(parsed.next().is_some_and(|res| res.is_err()));
+    assert_eq!(parsed.next(), Some(Ok(4)));
+    assert_eq!(parsed.next(), None);
+    assert!("1 2 x 4"
+        .split_every_n_times_parse::<i32, _>(" ", 1)
+        .collect::<Result<Vec<i32>, _>>()
+        .is_err());
+
+    let pairs: Vec<Result<f64, _>> = "1.5,2,x,4"
+        .split_every_n_times_parse::<f64, _>(',', 2)
+        .collect();
+    assert!(pairs.iter().all(Result::is_err));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_send_to_thread() {
+    let splitter: SplitEvery<String, String> =
+        SplitEvery::new(String::from("a,b,c,d,e"), String::from(","), 2);
+    let chunks: Vec<String> = std::thread::spawn(move || splitter.collect())
+        .join()
+        .unwrap();
+    assert_eq!(chunks, ["a,b", "c,d", "e"]);
+
+    let mut splitter: SplitEvery<Vec<u8>, [u8; 1]> =
+        Vec::from(*b"1 2 3").split_every_n_times([b' '], 1);
+    assert_eq!(splitter.next(), Some(Vec::from(*b"1")));
+    let rest: Vec<Vec<u8>> = std::thread::spawn(move || splitter.collect())
+        .join()
+        .unwrap();
+    assert_eq!(rest, [Vec::from(*b"2"), Vec::from(*b"3")]);
+}
+
+#[test]
+fn test_boxed_chunks() {
+    let text: &str = "ab,cd,é,,f";
+    for n in 0..4 {
+        let strings: Vec<String> = text.split_every_n_times(",", n).collect();
+        let boxed: Vec<Box<str>> = text.split_every_n_times_boxed(",", n).collect();
+        assert_eq!(boxed.len(), strings.len());
+        for (boxed, string) in boxed.iter().zip(&strings) {
+            assert_eq!(**boxed, **string);
+        }
+    }
+    let boxed: Vec<Box<str>> = String::from(text)
+        .split_every_n_times_boxed(',', 2)
+        .collect();
+    assert_eq!(boxed, [Box::from("ab,cd"), Box::from("é,"), Box::from("f")]);
+    assert!(core::mem::size_of::<Box<str>>() < core::mem::size_of::<String>());
+}
+
+#[test]
+fn test_strided() {
+    // Records of 3 items, each starting with a marker: 0 only counts on-stride.
+    let records: [u8; 12] = [0, 1, 2, 0, 0, 5, 0, 7, 0, 0, 9, 9];
+    assert_eq!(
+        records
+            .iter()
+            .copied()
+            .split_every_n_times_strided(0, 1, 3)
+            .collect::<Vec<Vec<u8>>>(),
+        [
+            Vec::new(),
+            Vec::from([1, 2]),
+            Vec::from([0, 5]),
+            Vec::from([7, 0]),
+            Vec::from([9, 9])
+        ]
+    );
+    assert_eq!(
+        records
+            .iter()
+            .copied()
+            .split_every_n_times_strided(0, 2, 3)
+            .collect::<Vec<Vec<u8>>>(),
+        [
+            Vec::from([0, 1, 2]),
+            Vec::from([0, 5, 0, 7, 0]),
+            Vec::from([9, 9])
+        ]
+    );
+    assert_eq!(
+        records
+            .iter()
+            .copied()
+            .split_every_n_times_strided(9, 1, 3)
+            .collect::<Vec<Vec<u8>>>(),
+        [Vec::from(records)]
+    );
+    for stride in [0, 1] {
+        assert_eq!(
+            records
+                .iter()
+                .copied()
+                .split_every_n_times_strided(0, 1, stride)
+                .collect::<Vec<Vec<u8>>>(),
+            SplitEveryIterImpl::split_every_n_times(records.iter().copied(), 0, 1)
+                .collect::<Vec<Vec<u8>>>()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_string_ref() {
+    let mut my_string: String = String::from("a,b,c,d,e");
+    let chunks: Vec<&str> = my_string.split_every_n_times_ref(",", 2).collect();
+    assert_eq!(chunks, ["a,b", "c,d", "e"]);
+    let first: &str = (&my_string).split_every_n_times_ref(',', 1).next().unwrap();
+    assert!(core::ptr::eq(first, &my_string[..1]));
+    let splitter: SplitEveryRef<&str, &str> = my_string.split_every_n_times_ref(",", 1);
+    assert_eq!(splitter.count(), 5);
+
+    my_string.push_str(",f");
+    assert_eq!(my_string, "a,b,c,d,e,f");
+    assert_eq!(
+        my_string
+            .split_every_n_times_ref(",", 3)
+            .collect::<Vec<&str>>(),
+        ["a,b,c", "d,e,f"]
+    );
+}
+
+#[test]
+fn test_require_pattern() {
+    for require in [false, true] {
+        let expected: &[&str] = if require { &[] } else { &["abc"] };
+        let splitter: SplitEvery<&str, &str> =
+            SplitEvery::new("abc", ",", 1).require_pattern(require);
+        assert!(splitter.size_hint().0 <= expected.len());
+        assert_eq!(splitter.clone().collect::<Vec<String>>(), expected);
+        assert_eq!(splitter.rev().collect::<Vec<String>>(), expected);
+        assert_eq!(
+            "abc"
+                .split_every_n_times_ref(",", 0)
+                .require_pattern(require)
+                .collect::<Vec<&str>>(),
+            expected
+        );
+        assert_eq!(
+            "abc"
+                .split_every_n_times_ref("", 1)
+                .require_pattern(require)
+                .collect::<Vec<&str>>(),
+            expected
+        );
+        let chunks: Vec<Vec<u8>> = SplitEvery::new(Vec::from(*b"abc"), [b','], 2)
+            .require_pattern(require)
+            .collect();
+        assert_eq!(chunks.len(), expected.len());
+        let chunks: Vec<&[u8]> = b"abc"
+            .as_slice()
+            .split_every_n_times_ref([b','], 1)
+            .with_max_splits(1)
+            .require_pattern(require)
+            .collect();
+        assert_eq!(chunks.len(), expected.len());
+
+        // Any occurrence keeps the input, even fewer than `n`.
+        for (text, n, chunks) in [
+            ("abc,", 1, &["abc"][..]),
+            ("a,b", 3, &["a,b"]),
+            ("a,b,c", 2, &["a,b", "c"]),
+        ] {
+            assert_eq!(
+                SplitEvery::new(text, ",", n)
+                    .require_pattern(require)
+                    .collect::<Vec<String>>(),
+                chunks
+            );
+            assert_eq!(
+                text.split_every_n_times_ref(",", n)
+                    .require_pattern(require)
+                    .rev()
+                    .count(),
+                chunks.len()
+            );
+            assert_eq!(
+                SplitEvery::new(text.as_bytes(), [b','], n)
+                    .require_pattern(require)
+                    .count(),
+                chunks.len()
+            );
+        }
+    }
+    assert_eq!(
+        SplitEvery::new("", ",", 1)
+            .keep_trailing_empty(true)
+            .require_pattern(true)
+            .next(),
+        None
+    );
+}
+
+#[test]
+fn test_chunks_remaining() {
+    let text: &str = "a,,b,c,é,d,";
+    let bytes: Vec<u8> = Vec::from(text.as_bytes());
+    for n in 0..4 {
+        for (skip_empty, keep_trailing_empty, require_pattern) in [
+            (false, false, false),
+            (true, false, false),
+            (false, true, false),
+            (false, false, true),
+        ] {
+            let mut splitter: SplitEvery<&str, &str> = SplitEvery::new(text, ",", n)
+                .skip_empty(skip_empty)
+                .keep_trailing_empty(keep_trailing_empty)
+                .require_pattern(require_pattern)
+                .with_max_splits(4);
+            let mut slices: SplitEvery<&[u8], [u8; 1]> =
+                SplitEvery::new(bytes.as_slice(), [b','], n)
+                    .skip_empty(skip_empty)
+                    .keep_trailing_empty(keep_trailing_empty)
+                    .require_pattern(require_pattern)
+                    .with_max_splits(4);
+            loop {
+                assert_eq!(splitter.chunks_remaining(), splitter.clone().count());
+                assert_eq!(slices.chunks_remaining(), slices.clone().count());
+                assert_eq!(splitter.chunks_remaining(), slices.chunks_remaining());
+                if splitter.next().is_none() {
+                    break;
+                }
+                slices.next();
+            }
+            assert_eq!(splitter.chunks_remaining(), 0);
+        }
+    }
+
+    let mut splitter: SplitEvery<&str, &str> = SplitEvery::new(text, ",", 1);
+    splitter.next_back();
+    assert_eq!(splitter.chunks_remaining(), 5);
+    assert_eq!(splitter.chunks_remaining(), splitter.count());
+}
+
+#[test]
+fn test_elem_ref() {
+    let nums: &[i32] = &[1, 0, 2, 3, 0, 0, 4];
+    let zero: i32 = 0;
+    for n in 0..4 {
+        let by_ref: Vec<Vec<i32>> = nums.split_every_n_times_elem_ref(&zero, n).collect();
+        assert_eq!(
+            by_ref,
+            nums.split_every_n_times(&[0][..], n).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            by_ref,
+            nums.split_every_n_times_elem(0, n).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            by_ref,
+            Vec::from(nums)
+                .split_every_n_times_elem_ref(&zero, n)
+                .collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(
+        nums.split_every_n_times_elem_ref(&zero, 1)
+            .collect::<Vec<Vec<i32>>>(),
+        [
+            Vec::from([1]),
+            Vec::from([2, 3]),
+            Vec::new(),
+            Vec::from([4])
+        ]
+    );
+}
+
+#[test]
+fn test_min_chunk_len() {
+    assert_eq!(
+        "a,b,cd,e"
+            .split_every_n_times_ref(",", 1)
+            .min_chunk_len(2)
+            .collect::<Vec<&str>>(),
+        ["a,b", "cd", "e"]
+    );
+    assert_eq!(
+        "x,y,z,,longer,w"
+            .split_every_n_times_ref(",", 1)
+            .min_chunk_len(4)
+            .collect::<Vec<&str>>(),
+        ["x,y,z", ",longer", "w"]
+    );
+    assert_eq!(
+        [1, 0, 2, 0, 3, 4, 5, 0, 6]
+            .as_slice()
+            .split_every_n_times_ref([0], 1)
+            .min_chunk_len(3)
+            .collect::<Vec<&[i32]>>(),
+        [&[1, 0, 2][..], &[3, 4, 5], &[6]]
+    );
+
+    let text: &str = "a,,bb,c,dddd,e,,f,gg, h ,";
+    let bytes: Vec<u8> = Vec::from(text.as_bytes());
+    for n in 1..3 {
+        for min in 0..6 {
+            for (skip_empty, trim, keep_trailing_empty, lossless) in [
+                (false, false, false, false),
+                (true, false, false, false),
+                (false, true, false, false),
+                (false, false, true, false),
+                (false, false, false, true),
+            ] {
+                let splitter: SplitEvery<&str, &str> = SplitEvery::new(text, ",", n)
+                    .min_chunk_len(min)
+                    .skip_empty(skip_empty)
+                    .trim_chunks(trim)
+                    .keep_trailing_empty(keep_trailing_empty)
+                    .lossless(lossless)
+                    .with_max_splits(min + 1);
+                let chunks: Vec<String> = splitter.clone().collect();
+                assert!(chunks.iter().rev().skip(1).all(|chunk| chunk.len() >= min));
+                let mut back: Vec<String> = splitter.clone().rev().collect();
+                back.reverse();
+                assert_eq!(back, chunks, "n={n} min={min}");
+                if !trim {
+                    let from_bytes: Vec<Vec<u8>> = SplitEvery::new(bytes.clone(), [b','], n)
+                        .min_chunk_len(min)
+                        .skip_empty(skip_empty)
+                        .keep_trailing_empty(keep_trailing_empty)
+                        .lossless(lossless)
+                        .with_max_splits(min + 1)
+                        .collect();
+                    let chunks: Vec<Vec<u8>> = chunks
+                        .iter()
+                        .map(|chunk| chunk.clone().into_bytes())
+                        .collect();
+                    assert_eq!(from_bytes, chunks, "n={n} min={min}");
+                }
+
+                // Alternating ends meets in the middle without losing or repeating chunks.
+                let mut splitter = splitter.clone();
+                let mut front: Vec<String> = Vec::new();
+                let mut back: Vec<String> = Vec::new();
+                loop {
+                    match front.len() + back.len() {
+                        len if len % 2 == 0 => match splitter.next() {
+                            Some(chunk) => front.push(chunk),
+                            None => break,
+                        },
+                        _ => match splitter.next_back() {
+                            Some(chunk) => back.push(chunk),
+                            None => break,
+                        },
+                    }
+                }
+                front.extend(back.into_iter().rev());
+                assert_eq!(front, chunks, "n={n} min={min}");
+            }
+        }
+    }
+
+    let splitter = SplitEvery::new("a,b,c,d,e,f", ",", 1)
+        .min_chunk_len(3)
+        .with_max_splits(2);
+    assert_eq!(
+        splitter.clone().collect::<Vec<String>>(),
+        ["a,b", "c,d,e,f"]
+    );
+    assert_eq!(splitter.rev().collect::<Vec<String>>(), ["c,d,e,f", "a,b"]);
+}
+
+#[test]
+fn test_next_back_from_end() {
+    use core::cell::Cell;
+
+    /// A `char` pattern counting forward searches.
+    struct Counted<'a>(char, &'a Cell<usize>);
+
+    impl StrPattern for Counted<'_> {
+        fn find_in(&self, haystack: &str) -> Option<Range<usize>> {
+            self.1.set(self.1.get() + 1);
+            self.0.find_in(haystack)
+        }
+
+        fn rfind_in(&self, haystack: &str) -> Option<Range<usize>> {
+            self.0.rfind_in(haystack)
+        }
+    }
+
+    let calls: Cell<usize> = Cell::new(0);
+    let text: String = "ab,".repeat(1000);
+    let mut splitter = SplitEvery::new(text.as_str(), Counted(',', &calls), 2);
+    assert_eq!(splitter.next_back().as_deref(), Some("ab,ab"));
+    let counted: usize = calls.get();
+    assert_eq!(splitter.by_ref().rev().count(), 499);
+    // Only the first chunk from the back counts the occurrences from the front.
+    assert_eq!(calls.get(), counted);
+
+    let alphabet: [char; 3] = ['a', 'b', ','];
+    for len in 0..=7u32 {
+        for mut code in 0..alphabet.len().pow(len) {
+            let mut text: String = String::new();
+            for _ in 0..len {
+                text.push(alphabet[code % alphabet.len()]);
+                code /= alphabet.len();
+            }
+            for pat in [",", "a,", "aa", "aba", "b"] {
+                for n in 1..4 {
+                    let expected: Vec<String> = SplitEvery::new(text.as_str(), pat, n).collect();
+                    let mut back: Vec<String> =
+                        SplitEvery::new(text.as_str(), pat, n).rev().collect();
+                    back.reverse();
+                    assert_eq!(back, expected, "{text:?} {pat:?} n={n}");
+
+                    let mut splitter = SplitEvery::new(text.as_str(), pat, n);
+                    let mut front: Vec<String> = Vec::new();
+                    let mut back: Vec<String> = Vec::new();
+                    loop {
+                        let (next, taken) = if (front.len() + back.len()) % 3 == 1 {
+                            (splitter.next(), &mut front)
+                        } else {
+                            (splitter.next_back(), &mut back)
+                        };
+                        let Some(chunk) = next else {
+                            break;
+                        };
+                        taken.push(chunk);
+                    }
+                    front.extend(back.into_iter().rev());
+                    assert_eq!(front, expected, "{text:?} {pat:?} n={n}");
+                }
+            }
+        }
+    }
+
+    // Seeking forgets how the occurrences left line up with `n`.
+    let mut splitter = SplitEvery::new("a,b,c,d,e,f,g", ',', 2);
+    assert_eq!(splitter.next_back().as_deref(), Some("g"));
+    splitter.seek_to(2);
+    let rest: Vec<String> = splitter.clone().collect();
+    assert_eq!(rest, ["b,c", "d,e", "f"]);
+    let mut back: Vec<String> = splitter.rev().collect();
+    back.reverse();
+    assert_eq!(back, rest);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_input_methods() {
+    let owned: String = "a,b,c,d".to_string();
+    let rest: &str = {
+        let mut splitter: SplitEvery<&String, &str> = SplitEvery::new(&owned, ",", 1);
+        splitter.next();
+        splitter.remainder()
+    };
+    assert_eq!(rest, "b,c,d");
+
+    let mut splitter: SplitEvery<Cow<str>, &str> = SplitEvery::new(Cow::from("a,b,c,d"), ",", 1);
+    let mut buf: String = String::new();
+    assert!(splitter.next_into(&mut buf));
+    assert_eq!(buf, "a");
+    assert_eq!(splitter.peek_chunk(), Some("b"));
+    assert_eq!(splitter.byte_offset(), 2);
+    assert_eq!(splitter.chunks_remaining(), 3);
+    splitter.seek_to(4);
+    assert_eq!(splitter.join_with("+"), "c+d");
+
+    let mut input: String = "a,b,c,d".to_string();
+    let mut splitter: SplitEvery<alloc::string::Drain<'_>, &str> =
+        SplitEvery::new(input.drain(..), ",", 2);
+    assert_eq!(splitter.occurrences_left(), 3);
+    splitter.seek_to(2);
+    assert_eq!(splitter.remainder(), "b,c,d");
+    assert_eq!(splitter.peek_chunk(), Some("b,c"));
+    splitter.reset();
+    assert_eq!(splitter.remainder(), "a,b,c,d");
+
+    let mut splitter: SplitEvery<[u8; 5], u8> = SplitEvery::new([1, 0, 2, 0, 3], 0, 1);
+    let mut buf: Vec<u8> = Vec::new();
+    assert!(splitter.next_into(&mut buf));
+    assert_eq!(buf, [1]);
+    assert_eq!(splitter.index(), 2);
+    assert_eq!(splitter.remainder(), [2, 0, 3]);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_bytes_input_methods() {
+    let mut splitter: SplitEvery<bytes::Bytes, u8> =
+        SplitEvery::new(bytes::Bytes::from_static(b"a\nb\nc"), b'\n', 1);
+    assert_eq!(splitter.peek_chunk().unwrap(), &b"a"[..]);
+    splitter.next();
+    assert_eq!(splitter.index(), 2);
+    assert_eq!(splitter.remainder(), &b"b\nc"[..]);
+    assert_eq!(splitter.chunks_remaining(), 2);
 }
@@ -0,0 +1,29 @@
+//! Splits iterators of borrowed items through the public API only, as a downstream crate
+//! would, so that the returned splitter's type can be named in a signature.
+#![cfg(feature = "std")]
+
+use split_every::prelude::*;
+
+#[test]
+fn test_borrowed_iter() {
+    fn split<'a>(
+        words: &'a [&'a str],
+    ) -> SplitEvery<Box<dyn FnMut() -> Option<&'a str> + 'a>, &'a str> {
+        words.iter().copied().split_every_n_times("is", 1)
+    }
+
+    let text: String = String::from("This is you This is me");
+    let words: Vec<&str> = text.split(' ').collect();
+    assert_eq!(
+        split(&words).collect::<Vec<Vec<&str>>>(),
+        vec![vec!["This"], vec!["you", "This"], vec!["me"]]
+    );
+
+    // The pattern may borrow local data as well.
+    let pat: &str = &text[5..7];
+    let splitter = words.iter().copied().split_every_n_times(pat, 2);
+    assert_eq!(
+        splitter.collect::<Vec<Vec<&str>>>(),
+        vec![vec!["This", "is", "you", "This"], vec!["me"]]
+    );
+}